@@ -0,0 +1,111 @@
+// Compares the `Lines<T>` + `FromStr` path against the byte-level toolkit on synthetic inputs
+// shaped like day_8 (three coordinates) and day_9 (two coordinates), and `Grid<T>` against
+// `ByteGrid` on a day_4 shaped grid.
+//
+//     cargo run --release -p common --example parse_bench [lines]
+
+use std::{env::args, fmt::Write as _, hint::black_box, time::Instant};
+
+use common::{ByteGrid, Grid, Input, IntegerLines, Lines, Pair, Tuple3, byte_lines, integers};
+
+const RUNS: usize = 5;
+
+fn generate(lines: usize, columns: usize) -> String {
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut input = String::new();
+    for _ in 0..lines {
+        for c in 0..columns {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            if c > 0 {
+                input.push(',');
+            }
+            write!(input, "{}", state % 100_000).unwrap();
+        }
+        input.push('\n');
+    }
+    input
+}
+
+fn time(name: &str, mut f: impl FnMut() -> usize) {
+    let mut best = f64::MAX;
+    let mut checksum = 0;
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        checksum = black_box(f());
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+    println!(
+        "  {name:<28} {:>9.3} ms  (checksum {checksum})",
+        best * 1000.0
+    );
+}
+
+fn main() -> common::Result<()> {
+    let lines = args().nth(1).map_or(Ok(1_000_000), |n| n.parse())?;
+
+    let day_8 = generate(lines, 3);
+    println!(
+        "day_8 shape, {lines} lines of x,y,z ({} bytes)",
+        day_8.len()
+    );
+    time("Lines<Tuple3<i64, ..>>", || {
+        let input = Lines::<Tuple3<i64, i64, i64>>::parse_reader(day_8.as_bytes()).unwrap();
+        input.lines.iter().map(|t| (t.0 + t.1 + t.2) as usize).sum()
+    });
+    time("IntegerLines<i64>", || {
+        let input = IntegerLines::<i64>::parse_reader(day_8.as_bytes()).unwrap();
+        input.iter().flatten().map(|&v| v as usize).sum()
+    });
+    time("byte_lines + integers", || {
+        byte_lines(day_8.as_bytes())
+            .flat_map(integers::<i64>)
+            .map(|v| v as usize)
+            .sum()
+    });
+
+    let day_9 = generate(lines, 2);
+    println!("day_9 shape, {lines} lines of x,y ({} bytes)", day_9.len());
+    time("Lines<Pair<usize, usize>>", || {
+        let input = Lines::<Pair<usize, usize>>::parse_reader(day_9.as_bytes()).unwrap();
+        input.lines.iter().map(|p| p.0 + p.1).sum()
+    });
+    time("IntegerLines<usize>", || {
+        let input = IntegerLines::<usize>::parse_reader(day_9.as_bytes()).unwrap();
+        input.iter().flatten().sum()
+    });
+    time("byte_lines + integers", || {
+        byte_lines(day_9.as_bytes())
+            .flat_map(integers::<usize>)
+            .sum()
+    });
+
+    let side = (lines as f64).sqrt() as usize;
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let mut grid = String::new();
+    for _ in 0..side {
+        for _ in 0..side {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            grid.push(if state.is_multiple_of(3) { '.' } else { '@' });
+        }
+        grid.push('\n');
+    }
+    println!("day_4 shape, {side}x{side} grid ({} bytes)", grid.len());
+    time("Grid<char>", || {
+        let input = Grid::<char>::parse_reader(grid.as_bytes()).unwrap();
+        input.width() * input.height()
+    });
+    time("Grid<u8>", || {
+        let input = Grid::<u8>::parse_reader(grid.as_bytes()).unwrap();
+        input.width() * input.height()
+    });
+    time("ByteGrid", || {
+        let input = ByteGrid::parse_reader(grid.as_bytes()).unwrap().grid;
+        input.width() * input.height()
+    });
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use std::{any::type_name, io::BufRead, ops::Range};
+
+use crate::{Error, Input, ParseError, Result, Snippet, anyhow, normalize::BOM};
+
+pub fn byte_lines(input: &[u8]) -> impl Iterator<Item = &[u8]> {
+    let body = (!input.is_empty()).then(|| input.strip_suffix(b"\n").unwrap_or(input));
+    body.into_iter()
+        .flat_map(|body| body.split(|&b| b == b'\n'))
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+}
+
+pub trait Integer: Copy {
+    const SIGNED: bool;
+
+    /// Scans an integer from the start of `bytes`, returning it and the number of bytes consumed
+    fn scan(bytes: &[u8]) -> Option<(Self, usize)>;
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {$(
+        impl Integer for $ty {
+            const SIGNED: bool = false;
+
+            fn scan(bytes: &[u8]) -> Option<(Self, usize)> {
+                let digits = bytes.strip_prefix(b"+").unwrap_or(bytes);
+                let sign = bytes.len() - digits.len();
+                let count = digits.iter().take_while(|b| b.is_ascii_digit()).count();
+                if count == 0 {
+                    return None;
+                }
+
+                let mut value: $ty = 0;
+                for &b in &digits[..count] {
+                    value = value.checked_mul(10)?.checked_add((b - b'0') as $ty)?;
+                }
+                Some((value, sign + count))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {$(
+        impl Integer for $ty {
+            const SIGNED: bool = true;
+
+            fn scan(bytes: &[u8]) -> Option<(Self, usize)> {
+                let (negative, digits) = match bytes.first() {
+                    Some(b'-') => (true, &bytes[1..]),
+                    Some(b'+') => (false, &bytes[1..]),
+                    _ => (false, bytes),
+                };
+                let sign = bytes.len() - digits.len();
+                let count = digits.iter().take_while(|b| b.is_ascii_digit()).count();
+                if count == 0 {
+                    return None;
+                }
+
+                // Accumulate towards the sign so that the minimum value does not overflow
+                let mut value: $ty = 0;
+                for &b in &digits[..count] {
+                    let digit = (b - b'0') as $ty;
+                    value = value.checked_mul(10)?;
+                    value = if negative {
+                        value.checked_sub(digit)?
+                    } else {
+                        value.checked_add(digit)?
+                    };
+                }
+                Some((value, sign + count))
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+pub fn parse_integer<T: Integer>(bytes: &[u8]) -> Option<T> {
+    match T::scan(bytes)? {
+        (value, len) if len == bytes.len() => Some(value),
+        _ => None,
+    }
+}
+
+// Finds the next run of digits from `pos`, along with a `-` directly before it which does not
+// follow another digit, so `3-5` is read as `3` and `5`. Returns its range and whether it has a sign.
+fn next_number(line: &[u8], mut pos: usize) -> Option<(Range<usize>, bool)> {
+    while pos < line.len() {
+        let negative = line[pos] == b'-'
+            && (pos == 0 || !line[pos - 1].is_ascii_digit())
+            && line.get(pos + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !line[pos].is_ascii_digit() {
+            pos += 1;
+            continue;
+        }
+
+        let digits = pos + negative as usize;
+        let end = digits
+            + line[digits..]
+                .iter()
+                .take_while(|b| b.is_ascii_digit())
+                .count();
+        return Some((pos..end, negative));
+    }
+    None
+}
+
+/// Yields every integer in `line`, for pulling numbers out of free text. This is lenient: runs of
+/// digits which overflow `T` are skipped, and unsigned types ignore a `-` so `-5` is read as `5`.
+/// `IntegerLines` reports both as errors instead.
+pub fn integers<T: Integer>(line: &[u8]) -> impl Iterator<Item = T> + '_ {
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        loop {
+            let (range, negative) = next_number(line, pos)?;
+            pos = range.end;
+            let start = range.start + (negative && !T::SIGNED) as usize;
+            if let Some(value) = parse_integer(&line[start..range.end]) {
+                return Some(value);
+            }
+        }
+    })
+}
+
+/// Lines of integers separated by any other characters, found like `integers` finds them. Unlike
+/// `integers`, a number which does not fit `T` is an error, as is a negative number for an
+/// unsigned `T`.
+// Integers are stored flat, a `Vec` per line costs more than the parsing itself
+pub struct IntegerLines<T> {
+    values: Vec<T>,
+    ends: Vec<usize>,
+}
+
+impl<T> IntegerLines<T> {
+    pub fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    pub fn line(&self, index: usize) -> &[T] {
+        let start = index.checked_sub(1).map_or(0, |i| self.ends[i]);
+        &self.values[start..self.ends[index]]
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.len()).map(|i| self.line(i))
+    }
+}
+
+impl<T: Integer> Input for IntegerLines<T> {
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut contents = Vec::new();
+        reader.read_to_end(&mut contents)?;
+
+        // Normalized like `Lines`, so trailing blank lines are not read as empty lines
+        let mut contents = contents.strip_prefix(BOM.as_bytes()).unwrap_or(&contents);
+        while let Some(rest) = contents.strip_suffix(b"\n") {
+            contents = rest.strip_suffix(b"\r").unwrap_or(rest);
+        }
+
+        let mut values = Vec::with_capacity(contents.len() / 4);
+        let mut ends = Vec::new();
+        for (i, line) in byte_lines(contents).enumerate() {
+            let mut pos = 0;
+            while let Some((range, negative)) = next_number(line, pos) {
+                pos = range.end;
+                match parse_integer(&line[range.clone()]) {
+                    Some(value) => values.push(value),
+                    None => return Err(number_error::<T>(i + 1, line, range, negative)),
+                }
+            }
+            ends.push(values.len());
+        }
+
+        Ok(Self { values, ends })
+    }
+}
+
+fn number_error<T: Integer>(
+    number: usize,
+    line: &[u8],
+    range: Range<usize>,
+    negative: bool,
+) -> Error {
+    let text = String::from_utf8_lossy(line);
+    let column = String::from_utf8_lossy(&line[..range.start])
+        .chars()
+        .count();
+    let token = String::from_utf8_lossy(&line[range.clone()]).into_owned();
+    let snippet = Snippet::span(number, &text, column, range.len());
+
+    if negative && !T::SIGNED {
+        anyhow!("negative number for unsigned {}", type_name::<T>())
+            .context(ParseError::InvalidToken {
+                line: number,
+                column: column + 1,
+                token,
+            })
+            .context(snippet)
+    } else {
+        anyhow!("number out of range for {}", type_name::<T>())
+            .context(ParseError::OutOfRange {
+                line: number,
+                column: column + 1,
+                token,
+            })
+            .context(snippet)
+    }
+}
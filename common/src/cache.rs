@@ -0,0 +1,133 @@
+use std::{
+    env, fs,
+    io::Read,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Answer, Context as _, Result};
+
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+pub struct Cache {
+    path: PathBuf,
+}
+
+impl Cache {
+    /// Hashes the input in chunks, for inputs too large to hold in memory
+    pub fn new<R: Read>(mut input: R) -> Result<Self> {
+        let exe = env::current_exe().context("unable to locate current executable")?;
+        let name = exe
+            .file_stem()
+            .context("executable has no file name")?
+            .to_string_lossy();
+
+        // Any rebuild changes the executable's size or modification time, so there is no need to
+        // read the whole binary
+        let metadata = fs::metadata(&exe).context("unable to read current executable")?;
+        let modified = metadata
+            .modified()
+            .context("unable to read current executable")?
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        let mut key = fnv1a(0xcbf2_9ce4_8422_2325, &metadata.len().to_le_bytes());
+        key = fnv1a(key, &modified.as_nanos().to_le_bytes());
+        let mut chunk = vec![0; 1 << 16];
+        loop {
+            let len = input.read(&mut chunk).context("unable to read input")?;
+            if len == 0 {
+                break;
+            }
+            key = fnv1a(key, &chunk[..len]);
+        }
+        let dir = exe.parent().context("executable has no parent")?;
+
+        Ok(Self {
+            path: dir.join("cache").join(format!("{name}-{key:016x}")),
+        })
+    }
+
+    fn load(&self) -> Option<Vec<Answer>> {
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let mut lines = contents.lines();
+
+        let mut answers = Vec::new();
+        while let Some(header) = lines.next() {
+            let mut pieces = header.split(' ');
+            let name = pieces.next()?.to_string();
+            let elapsed = Duration::try_from_secs_f64(pieces.next()?.parse().ok()?).ok()?;
+            let solution = match pieces.next()? {
+                "unsolved" => None,
+                count => {
+                    let count = count.parse::<usize>().ok()?;
+                    let solution = lines.by_ref().take(count).collect::<Vec<_>>();
+                    if solution.len() < count {
+                        return None;
+                    }
+                    Some(solution.join("\n"))
+                }
+            };
+
+            answers.push(Answer {
+                name,
+                elapsed,
+                solution,
+            });
+        }
+
+        // An empty or truncated entry is a miss rather than a run with no answers
+        (!answers.is_empty()).then_some(answers)
+    }
+
+    /// Prints the cached answers, returning whether there were any
+    pub fn replay(&self) -> bool {
+        let Some(answers) = self.load() else {
+            return false;
+        };
+        for answer in &answers {
+            answer.print(true);
+        }
+        true
+    }
+
+    pub fn store(&self, answers: &[Answer]) -> Result<()> {
+        let mut contents = String::new();
+        for answer in answers {
+            let Some(solution) = &answer.solution else {
+                contents.push_str(&format!("{} 0 unsolved\n", answer.name));
+                continue;
+            };
+
+            contents.push_str(&format!(
+                "{} {} {}\n",
+                answer.name,
+                answer.elapsed.as_secs_f64(),
+                solution.lines().count(),
+            ));
+            for line in solution.lines() {
+                contents.push_str(line);
+                contents.push('\n');
+            }
+        }
+
+        let dir = self.path.parent().context("cache path has no parent")?;
+        fs::create_dir_all(dir).context("unable to create cache directory")?;
+        // Write then rename so an interrupted run never leaves a truncated entry
+        let temp = self.path.with_extension(format!(
+            "{}.tmp",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_nanos()
+        ));
+        fs::write(&temp, contents).context("unable to write cache entry")?;
+        fs::rename(&temp, &self.path).context("unable to write cache entry")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use crate::{Context as _, Error, Result};
+
+fn parse_field<T>(s: &str, name: &str) -> Result<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    s.parse()
+        .map_err(Error::from)
+        .with_context(|| format!("invalid {name} element '{s}'"))
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Pair<A, B, const SEP: char = ','>(pub A, pub B);
+
+impl<A, B, const SEP: char> FromStr for Pair<A, B, SEP>
+where
+    A: FromStr,
+    B: FromStr,
+    Error: From<A::Err> + From<B::Err>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (a, b) = s
+            .split_once(SEP)
+            .with_context(|| format!("expected '{SEP}' separator in '{s}'"))?;
+        Ok(Self(parse_field(a, "first")?, parse_field(b, "second")?))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct Tuple3<A, B, C, const SEP: char = ','>(pub A, pub B, pub C);
+
+impl<A, B, C, const SEP: char> FromStr for Tuple3<A, B, C, SEP>
+where
+    A: FromStr,
+    B: FromStr,
+    C: FromStr,
+    Error: From<A::Err> + From<B::Err> + From<C::Err>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (a, rest) = s
+            .split_once(SEP)
+            .with_context(|| format!("expected '{SEP}' separator in '{s}'"))?;
+        let (b, c) = rest
+            .split_once(SEP)
+            .with_context(|| format!("expected second '{SEP}' separator in '{s}'"))?;
+        Ok(Self(
+            parse_field(a, "first")?,
+            parse_field(b, "second")?,
+            parse_field(c, "third")?,
+        ))
+    }
+}
@@ -0,0 +1,149 @@
+use std::{
+    fmt,
+    num::{IntErrorKind, ParseIntError},
+};
+
+use crate::Error;
+
+/// Lines and columns are 1-based, matching `Snippet`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    UnexpectedEnd {
+        line: usize,
+        expected: String,
+    },
+    InvalidToken {
+        line: usize,
+        column: usize,
+        token: String,
+    },
+    RaggedGrid {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    MissingSection {
+        line: usize,
+        name: String,
+    },
+    OutOfRange {
+        line: usize,
+        column: usize,
+        token: String,
+    },
+}
+
+impl ParseError {
+    /// Classifies a token which failed to parse, integers which overflow are out of range
+    pub fn token(line: usize, column: usize, token: &str, cause: &Error) -> Self {
+        let token = token.to_string();
+        match cause
+            .downcast_ref::<ParseIntError>()
+            .map(ParseIntError::kind)
+        {
+            Some(IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => Self::OutOfRange {
+                line,
+                column,
+                token,
+            },
+            _ => Self::InvalidToken {
+                line,
+                column,
+                token,
+            },
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        match self {
+            Self::UnexpectedEnd { line, .. }
+            | Self::InvalidToken { line, .. }
+            | Self::RaggedGrid { line, .. }
+            | Self::MissingSection { line, .. }
+            | Self::OutOfRange { line, .. } => *line,
+        }
+    }
+
+    /// Moves the error down by `lines`, like `Snippet::offset`
+    pub fn offset(&mut self, lines: usize) {
+        match self {
+            Self::UnexpectedEnd { line, .. }
+            | Self::InvalidToken { line, .. }
+            | Self::RaggedGrid { line, .. }
+            | Self::MissingSection { line, .. }
+            | Self::OutOfRange { line, .. } => *line += lines,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd { line, expected } => {
+                write!(
+                    f,
+                    "unexpected end of input at line {line}, expected {expected}"
+                )
+            }
+            Self::InvalidToken {
+                line,
+                column,
+                token,
+            } => write!(f, "invalid token '{token}' at line {line}, column {column}"),
+            Self::RaggedGrid {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "uneven grid lines, expected {expected} columns but found {found} at line {line}"
+            ),
+            Self::MissingSection { line, name } => {
+                write!(f, "missing {name}, input ended at line {line}")
+            }
+            Self::OutOfRange {
+                line,
+                column,
+                token,
+            } => write!(
+                f,
+                "value '{token}' out of range at line {line}, column {column}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    ShapeMismatch {
+        width: usize,
+        height: usize,
+        len: usize,
+    },
+    OutOfBounds {
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ShapeMismatch { width, height, len } => {
+                write!(f, "{len} elements do not fill a {width}x{height} grid")
+            }
+            Self::OutOfBounds {
+                x,
+                y,
+                width,
+                height,
+            } => write!(f, "({x}, {y}) is outside a {width}x{height} grid"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
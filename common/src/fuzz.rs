@@ -0,0 +1,146 @@
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::{BorrowedInput, Result, bail, normalize};
+
+const EXTRA_CHARS: &[char] = &[
+    ' ', '\n', '-', '+', ',', ':', '0', '9', 'x', '#', '.', '\u{e9}',
+];
+const SHOWN_PANICS: usize = 5;
+
+thread_local! {
+    // The last panic message on this thread while it is fuzzing, `None` while it is not
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+// The hook stays installed, panics on threads which are not fuzzing still reach the previous one.
+// Recording per thread keeps fuzzing in parallel tests from capturing each other's panics.
+fn install_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let fuzzing = PANIC.with_borrow_mut(|message| {
+                message.as_mut().map(|m| *m = info.to_string()).is_some()
+            });
+            if !fuzzing {
+                hook(info);
+            }
+        }));
+    });
+}
+
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n.max(1) as u64) as usize
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.below(items.len())]
+    }
+}
+
+fn mutate(rng: &mut Rng, chars: &mut Vec<char>, alphabet: &[char]) {
+    let pos = rng.below(chars.len() + 1);
+    match rng.below(7) {
+        0 if pos < chars.len() => chars[pos] = rng.pick(alphabet),
+        1 if pos < chars.len() => {
+            chars.remove(pos);
+        }
+        2 => chars.insert(pos, rng.pick(alphabet)),
+        3 => chars.truncate(pos),
+        4 => {
+            // Duplicate or drop the line containing `pos`
+            let start = chars[..pos]
+                .iter()
+                .rposition(|&c| c == '\n')
+                .map_or(0, |i| i + 1);
+            let end = chars[pos..]
+                .iter()
+                .position(|&c| c == '\n')
+                .map_or(chars.len(), |i| pos + i + 1);
+            if rng.below(2) == 0 {
+                chars.drain(start..end);
+            } else {
+                let line = chars[start..end].to_vec();
+                chars.splice(start..start, line);
+            }
+        }
+        5 => {
+            let count = rng.below(16);
+            let garbage = (0..count).map(|_| rng.pick(alphabet)).collect::<Vec<_>>();
+            chars.splice(pos..pos, garbage);
+        }
+        _ => {
+            *chars = (0..rng.below(64)).map(|_| rng.pick(alphabet)).collect();
+        }
+    }
+}
+
+/// Parses `cases` randomly mutated copies of `input` and fails if parsing any of them panics.
+/// The cases share one buffer, which is leaked since `I` may borrow from it for `'static`.
+pub fn fuzz<I: BorrowedInput<'static>>(input: &str, cases: usize) -> Result<()> {
+    let mut alphabet = input.chars().collect::<Vec<_>>();
+    alphabet.sort_unstable();
+    alphabet.dedup();
+    alphabet.extend_from_slice(EXTRA_CHARS);
+    let original = input.chars().collect::<Vec<_>>();
+
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let mut buffer = String::new();
+    let mut ranges = Vec::with_capacity(cases);
+    for _ in 0..cases {
+        let mut chars = original.clone();
+        for _ in 0..=rng.below(3) {
+            mutate(&mut rng, &mut chars, &alphabet);
+        }
+        let text = chars.into_iter().collect::<String>();
+        let start = buffer.len();
+        buffer.push_str(&normalize(&text, false));
+        ranges.push(start..buffer.len());
+    }
+    let buffer: &'static str = buffer.leak();
+
+    install_hook();
+    PANIC.set(Some(String::new()));
+    let mut errors = 0;
+    let mut panics = Vec::new();
+    for range in ranges {
+        let case = &buffer[range];
+        match panic::catch_unwind(AssertUnwindSafe(|| I::parse_str(case).is_err())) {
+            Ok(true) => errors += 1,
+            Ok(false) => (),
+            Err(_) => {
+                let message = PANIC.with_borrow_mut(|m| m.replace(String::new()));
+                panics.push((case, message.unwrap_or_default()));
+            }
+        }
+    }
+    PANIC.set(None);
+
+    println!(
+        "Fuzzed {cases} inputs: {} parsed, {errors} rejected, {} panicked",
+        cases - errors - panics.len(),
+        panics.len(),
+    );
+    for (case, message) in panics.iter().take(SHOWN_PANICS) {
+        println!("\n{message}\ninput:\n{case}");
+    }
+    if !panics.is_empty() {
+        bail!("parser panicked on {} of {cases} inputs", panics.len());
+    }
+
+    Ok(())
+}
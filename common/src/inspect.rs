@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use crate::integers;
+
+pub fn inspect(contents: &[u8]) {
+    let text = String::from_utf8_lossy(contents);
+    let lines = text.split_terminator('\n').collect::<Vec<_>>();
+
+    let mut min_width = usize::MAX;
+    let mut max_width = 0;
+    let mut crlf_lines = 0;
+    let mut trailing_whitespace_lines = 0;
+    let mut sections = 0;
+    let mut in_section = false;
+    let mut histogram = BTreeMap::new();
+    let mut number_count = 0;
+    let mut min_number = i128::MAX;
+    let mut max_number = i128::MIN;
+
+    for line in &lines {
+        let line = match line.strip_suffix('\r') {
+            Some(line) => {
+                crlf_lines += 1;
+                line
+            }
+            None => line,
+        };
+
+        let width = line.chars().count();
+        min_width = usize::min(min_width, width);
+        max_width = usize::max(max_width, width);
+
+        if line.ends_with(char::is_whitespace) {
+            trailing_whitespace_lines += 1;
+        }
+
+        if line.is_empty() {
+            in_section = false;
+        } else if !in_section {
+            in_section = true;
+            sections += 1;
+        }
+
+        for c in line.chars() {
+            *histogram.entry(c).or_insert(0usize) += 1;
+        }
+
+        for number in integers::<i128>(line.as_bytes()) {
+            number_count += 1;
+            min_number = i128::min(min_number, number);
+            max_number = i128::max(max_number, number);
+        }
+    }
+
+    println!("Input inspection:");
+    println!("  {} bytes, {} lines", contents.len(), lines.len());
+    if !lines.is_empty() {
+        println!(
+            "  line widths: min {min_width}, max {max_width}{}",
+            if min_width == max_width {
+                ""
+            } else {
+                " (ragged)"
+            },
+        );
+    }
+    println!("  sections: {sections}");
+    if number_count > 0 {
+        println!("  numbers: {number_count}, min {min_number}, max {max_number}");
+    } else {
+        println!("  numbers: none");
+    }
+    println!("  lines with trailing whitespace: {trailing_whitespace_lines}");
+    println!("  lines ending in CRLF: {crlf_lines}");
+    println!(
+        "  final newline: {}",
+        if contents.ends_with(b"\n") {
+            "present"
+        } else {
+            "missing"
+        },
+    );
+    if contents.starts_with(b"\xef\xbb\xbf") {
+        println!("  starts with a UTF-8 byte order mark");
+    }
+    if text.contains(char::REPLACEMENT_CHARACTER) {
+        println!("  contains invalid UTF-8");
+    }
+    println!("  characters:");
+    for (c, count) in histogram {
+        println!("    {c:?}: {count}");
+    }
+}
@@ -1,18 +1,69 @@
+mod bytes;
+mod cache;
+mod delimited;
+mod error;
+mod fuzz;
+mod inspect;
+mod normalize;
+mod options;
+mod point;
+mod sections;
+mod snippet;
+mod stream;
+mod table;
+mod tokens;
+mod watch;
+
 use std::{
-    env::args,
-    fmt::Display,
-    fs::File,
+    fmt::{self, Display},
+    fs::{self, File},
     io::{BufRead, BufReader},
+    ops::{Index, IndexMut},
     str::FromStr,
-    time::Instant,
+    time::{Duration, Instant},
+};
+
+pub use anyhow::{Context, Error, Result, anyhow, bail};
+pub use common_derive::{Input, Parse};
+
+pub use self::{
+    bytes::{Integer, IntegerLines, byte_lines, integers, parse_integer},
+    delimited::{Pair, Tuple3},
+    error::{GridError, ParseError},
+    fuzz::fuzz,
+    normalize::{Trimmed, normalize, read_normalized},
+    point::{Direction, Point2, Vec2},
+    sections::{Section, SectionCursor, Sections, split_sections},
+    snippet::Snippet,
+    stream::Stream,
+    table::{Group, Table},
+    tokens::{KeyedLines, Tokens, keyed_lines},
 };
 
-pub use anyhow::{Context, Error, Result, bail};
+use self::{
+    cache::Cache,
+    inspect::inspect,
+    normalize::{BOM, normalize_owned},
+    options::Options,
+    watch::watch,
+};
+
+const FUZZ_CASES: usize = 2000;
 
 pub trait Input: Sized {
     fn parse_reader<R: BufRead>(reader: R) -> Result<Self>;
 }
 
+pub trait BorrowedInput<'a>: Sized {
+    fn parse_str(input: &'a str) -> Result<Self>;
+}
+
+impl<T: Input> BorrowedInput<'_> for T {
+    fn parse_str(input: &str) -> Result<Self> {
+        T::parse_reader(input.as_bytes())
+    }
+}
+
 pub struct Lines<T> {
     pub lines: Vec<T>,
 }
@@ -22,33 +73,91 @@ where
     Error: From<T::Err>,
 {
     fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, false)?;
         let mut lines = Vec::new();
 
-        for line in reader.lines() {
-            lines.push(line?.parse::<T>()?);
+        for (i, line) in contents.lines().enumerate() {
+            lines.push(parse_line(i + 1, line)?);
         }
 
         Ok(Self { lines })
     }
 }
 
-pub struct List<T> {
+fn parse_line<T>(number: usize, line: &str) -> Result<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    line.parse::<T>().map_err(|e| {
+        let e = Error::from(e);
+        let error = ParseError::token(number, 1, line, &e);
+        e.context(error).context(Snippet::line(number, line))
+    })
+}
+
+fn parse_element<T>(contents: &str, element: &str) -> Result<T>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    element.parse().map_err(|e| {
+        let e = Error::from(e);
+        let start = element.as_ptr().addr() - contents.as_ptr().addr();
+        let snippet = Snippet::locate(contents, start, start + element.len());
+        let error = ParseError::token(snippet.line_number(), snippet.column(), element, &e);
+        e.context(error).context(snippet)
+    })
+}
+
+fn parse_elements<'a, T>(
+    contents: &'a str,
+    elements: impl Iterator<Item = &'a str>,
+) -> Result<Vec<T>>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+{
+    elements
+        .map(|element| parse_element(contents, element))
+        .collect()
+}
+
+pub struct List<T, const SEP: char = ',', const TRIM: bool = false> {
+    pub elements: Vec<T>,
+}
+
+impl<T: FromStr, const SEP: char, const TRIM: bool> Input for List<T, SEP, TRIM>
+where
+    Error: From<T::Err>,
+{
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, false)?;
+
+        let elements = contents
+            .trim_end_matches('\n')
+            .split(SEP)
+            .map(|e| if TRIM { e.trim() } else { e });
+
+        Ok(Self {
+            elements: parse_elements(&contents, elements)?,
+        })
+    }
+}
+
+pub struct WhitespaceList<T> {
     pub elements: Vec<T>,
 }
 
-impl<T: FromStr> Input for List<T>
+impl<T: FromStr> Input for WhitespaceList<T>
 where
     Error: From<T::Err>,
 {
-    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self> {
-        let mut contents = String::new();
-        reader.read_to_string(&mut contents)?;
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, false)?;
+
         Ok(Self {
-            elements: contents
-                .trim_end_matches(['\n', '\r'])
-                .split(',')
-                .map(str::parse)
-                .collect::<Result<Vec<_>, _>>()?,
+            elements: parse_elements(&contents, contents.split_whitespace())?,
         })
     }
 }
@@ -61,14 +170,32 @@ pub struct Grid<T> {
 }
 
 impl<T> Grid<T> {
+    #[track_caller]
     pub fn from_elements(width: usize, height: usize, elements: Vec<T>) -> Self {
-        assert_eq!(width * height, elements.len());
+        match Self::try_from_elements(width, height, elements) {
+            Ok(grid) => grid,
+            Err(e) => panic!("{e}"),
+        }
+    }
 
-        Self {
+    pub fn try_from_elements(
+        width: usize,
+        height: usize,
+        elements: Vec<T>,
+    ) -> Result<Self, GridError> {
+        if width.checked_mul(height) != Some(elements.len()) {
+            return Err(GridError::ShapeMismatch {
+                width,
+                height,
+                len: elements.len(),
+            });
+        }
+
+        Ok(Self {
             width,
             height,
             elements,
-        }
+        })
     }
 
     pub fn default(width: usize, height: usize) -> Self
@@ -106,9 +233,82 @@ impl<T> Grid<T> {
         }
     }
 
+    #[track_caller]
     pub fn set(&mut self, x: usize, y: usize, value: T) {
-        assert!(x < self.width && y < self.height);
-        self.elements[x + y * self.width] = value;
+        if let Err(e) = self.try_set(x, y, value) {
+            panic!("{e}");
+        }
+    }
+
+    pub fn try_set(&mut self, x: usize, y: usize, value: T) -> Result<(), GridError> {
+        let error = self.out_of_bounds(Point2::new(x, y));
+        let element = self.get_mut(x, y).ok_or(error)?;
+        *element = value;
+        Ok(())
+    }
+
+    fn out_of_bounds(&self, point: Point2) -> GridError {
+        GridError::OutOfBounds {
+            x: point.x,
+            y: point.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    /// Moves `point` by `offset`, returning `None` if it leaves the grid
+    pub fn step(&self, point: Point2, offset: impl Into<Vec2>) -> Option<Point2> {
+        point
+            .checked_add(offset)
+            .filter(|&point| self.contains(point))
+    }
+
+    /// The up to 4 orthogonally adjacent elements, not including `point` itself
+    pub fn neighbors4(&self, point: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        self.neighbors(point, &Direction::CARDINAL)
+    }
+
+    /// The up to 8 orthogonally and diagonally adjacent elements, not including `point` itself
+    pub fn neighbors8(&self, point: Point2) -> impl Iterator<Item = (Point2, &T)> {
+        self.neighbors(point, &Direction::ALL)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        point: Point2,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point2, &'a T)> {
+        directions
+            .iter()
+            .filter_map(move |&d| self.step(point, d))
+            .map(|p| (p, &self[p]))
+    }
+
+    /// Every element within a Chebyshev distance of `radius`, not including `point` itself
+    pub fn neighbors_within(
+        &self,
+        point: Point2,
+        radius: usize,
+    ) -> impl Iterator<Item = (Point2, &T)> {
+        self.neighborhood(point.x, point.y, radius)
+            .map(Point2::from)
+            .filter(move |&p| p != point)
+            .map(|p| (p, &self[p]))
+    }
+
+    /// Renders one line per row, each ending in a newline
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            let row = &self.elements[y * self.width..(y + 1) * self.width];
+            text.extend(row.iter().map(&mut f));
+            text.push('\n');
+        }
+        text
     }
 
     pub fn iter(&self) -> Region {
@@ -130,6 +330,49 @@ impl<T> Grid<T> {
     }
 }
 
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    #[track_caller]
+    fn index(&self, point: Point2) -> &T {
+        match self.get(point.x, point.y) {
+            Some(element) => element,
+            None => panic!("{}", self.out_of_bounds(point)),
+        }
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    #[track_caller]
+    fn index_mut(&mut self, point: Point2) -> &mut T {
+        let error = self.out_of_bounds(point);
+        match self.get_mut(point.x, point.y) {
+            Some(element) => element,
+            None => panic!("{error}"),
+        }
+    }
+}
+
+// The inverse of parsing, so `parse -> render` round-trips for normalized input
+impl<T: Copy + Into<char>> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&e| e.into()))
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = (0..self.height)
+            .map(|y| &self.elements[y * self.width..(y + 1) * self.width])
+            .collect::<Vec<_>>();
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("rows", &rows)
+            .finish()
+    }
+}
+
 pub struct Region {
     x: usize,
     y: usize,
@@ -169,67 +412,402 @@ impl Iterator for Region {
     }
 }
 
+// Without a fill value every line must be the same length, with one short lines are padded to
+// the longest. Also returns the length of each line before padding.
+fn parse_grid<T>(contents: &str, fill: Option<char>) -> Result<(Grid<T>, Vec<usize>)>
+where
+    T: TryFrom<char>,
+    Error: From<T::Error>,
+{
+    let lengths = contents
+        .lines()
+        .map(|line| line.chars().count())
+        .collect::<Vec<_>>();
+    let width = match fill {
+        Some(_) => lengths.iter().max(),
+        None => lengths.first(),
+    }
+    .copied()
+    .ok_or_else(|| ParseError::UnexpectedEnd {
+        line: 1,
+        expected: "grid rows".to_string(),
+    })?;
+
+    let mut elements = Vec::new();
+    for (y, (line, &len)) in contents.lines().zip(&lengths).enumerate() {
+        if fill.is_none() && len != width {
+            return Err(Error::new(ParseError::RaggedGrid {
+                line: y + 1,
+                expected: width,
+                found: len,
+            }))
+            .context(Snippet::span(y + 1, line, usize::min(width, len), 1));
+        }
+
+        for (x, c) in line.chars().enumerate() {
+            let element = T::try_from(c).map_err(|e| {
+                let e = Error::from(e);
+                let error = ParseError::token(y + 1, x + 1, &c.to_string(), &e);
+                e.context(error).context(Snippet::span(y + 1, line, x, 1))
+            })?;
+            elements.push(element);
+        }
+        if let Some(fill) = fill {
+            for _ in len..width {
+                let element = T::try_from(fill)
+                    .map_err(Error::from)
+                    .with_context(|| format!("invalid fill value {fill:?}"))?;
+                elements.push(element);
+            }
+        }
+    }
+
+    let grid = Grid {
+        width,
+        height: lengths.len(),
+        elements,
+    };
+    Ok((grid, lengths))
+}
+
+// `Grid<u8>` goes through this too, decoding every char before narrowing it to a byte. `ByteGrid`
+// skips the decoding for ASCII input.
 impl<T> Input for Grid<T>
 where
     T: TryFrom<char>,
     Error: From<T::Error>,
 {
     fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
-        let mut width = None;
-        let mut height = 0;
-        let mut elements = Vec::new();
+        let contents = read_normalized(reader, false)?;
+        Ok(parse_grid(&contents, None)?.0)
+    }
+}
 
-        for line in reader.lines() {
-            let line = line?;
+/// A grid parsed from lines of different lengths, padding short lines on the right with `FILL`
+pub struct PaddedGrid<T, const FILL: char = ' '> {
+    pub grid: Grid<T>,
+    pub line_lengths: Vec<usize>,
+}
 
-            if width.is_some_and(|w| w != line.len()) {
-                bail!("uneven grid lines");
-            }
-            width = Some(line.len());
-            height += 1;
+impl<T, const FILL: char> Input for PaddedGrid<T, FILL>
+where
+    T: TryFrom<char>,
+    Error: From<T::Error>,
+{
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, false)?;
+        let (grid, line_lengths) = parse_grid(&contents, Some(FILL))?;
+        Ok(Self { grid, line_lengths })
+    }
+}
+
+/// A grid of ASCII bytes, parsed without decoding characters
+pub struct ByteGrid {
+    pub grid: Grid<u8>,
+}
+
+impl Input for ByteGrid {
+    fn parse_reader<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut contents = Vec::new();
+        reader.read_to_end(&mut contents)?;
+        Self::parse_bytes(&contents)
+    }
+}
 
-            for c in line.chars() {
-                elements.push(T::try_from(c)?);
+impl ByteGrid {
+    /// Parses without requiring valid UTF-8, any non-ASCII byte is reported with its position
+    pub fn parse_bytes(contents: &[u8]) -> Result<Self> {
+        let contents = contents.strip_prefix(BOM.as_bytes()).unwrap_or(contents);
+
+        let mut lines = byte_lines(contents).collect::<Vec<_>>();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        let width = lines
+            .first()
+            .ok_or_else(|| ParseError::UnexpectedEnd {
+                line: 1,
+                expected: "grid rows".to_string(),
+            })?
+            .len();
+
+        let mut elements = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            if let Some(x) = line.iter().position(|b| !b.is_ascii()) {
+                let text = String::from_utf8_lossy(line);
+                let column = String::from_utf8_lossy(&line[..x]).chars().count();
+                return Err(anyhow!("expected ASCII"))
+                    .context(ParseError::InvalidToken {
+                        line: y + 1,
+                        column: column + 1,
+                        token: text.chars().nth(column).unwrap_or_default().to_string(),
+                    })
+                    .context(Snippet::span(y + 1, &text, column, 1));
+            }
+            if line.len() != width {
+                let text = String::from_utf8_lossy(line);
+                return Err(Error::new(ParseError::RaggedGrid {
+                    line: y + 1,
+                    expected: width,
+                    found: line.len(),
+                }))
+                .context(Snippet::span(
+                    y + 1,
+                    &text,
+                    usize::min(width, line.len()),
+                    1,
+                ));
             }
+            elements.extend_from_slice(line);
         }
 
         Ok(Self {
-            width: width.context("empty grid input")?,
-            height,
-            elements,
+            grid: Grid {
+                width,
+                height: lines.len(),
+                elements,
+            },
         })
     }
 }
 
+pub struct Unsolved;
+
+pub trait Solution {
+    fn solution(&self) -> Option<String>;
+}
+
+impl<T: Display> Solution for T {
+    fn solution(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+impl Solution for Unsolved {
+    fn solution(&self) -> Option<String> {
+        None
+    }
+}
+
+struct Answer {
+    name: String,
+    elapsed: Duration,
+    solution: Option<String>,
+}
+
+impl Answer {
+    fn print(&self, cached: bool) {
+        if let Some(solution) = &self.solution {
+            println!(
+                "Solved part {} in {} seconds{}",
+                self.name,
+                self.elapsed.as_secs_f32(),
+                if cached { " (cached)" } else { "" },
+            );
+            println!("{solution}");
+        } else {
+            println!("Part {} is not implemented", self.name);
+        }
+    }
+}
+
+fn time_part<O: Solution>(name: &str, part: impl FnOnce() -> O) -> Answer {
+    let start = Instant::now();
+    let solution = part();
+    Answer {
+        name: name.to_string(),
+        elapsed: start.elapsed(),
+        solution: solution.solution(),
+    }
+}
+
+fn run_part<O: Solution>(name: &str, part: impl FnOnce() -> O) -> Answer {
+    let answer = time_part(name, part);
+    answer.print(false);
+    answer
+}
+
+fn open_input(options: &Options) -> Result<BufReader<File>> {
+    let file = File::open(&options.input).context("unable to open input file")?;
+    Ok(BufReader::new(file))
+}
+
+// Handles the flags shared by every runner. Returns `None` once a flag has done all the work or
+// the answers were replayed from the cache, otherwise the cache to store new answers in.
+fn start<I: BorrowedInput<'static>>() -> Result<Option<(Options, Option<Cache>)>> {
+    let options = Options::from_args()?;
+    if options.watch {
+        watch(&options)?;
+        return Ok(None);
+    }
+
+    if options.inspect || options.fuzz {
+        let contents = fs::read(&options.input).context("unable to open input file")?;
+        if options.inspect {
+            inspect(&contents);
+        }
+        if options.fuzz {
+            fuzz::<I>(&String::from_utf8_lossy(&contents), FUZZ_CASES)?;
+            return Ok(None);
+        }
+    }
+
+    let cache = if options.no_cache {
+        None
+    } else {
+        Some(Cache::new(open_input(&options)?)?)
+    };
+    if cache.as_ref().is_some_and(Cache::replay) {
+        return Ok(None);
+    }
+
+    Ok(Some((options, cache)))
+}
+
+fn run<I, S, F, P1, O1, P2, O2>(
+    prepare: F,
+    report_prepare: bool,
+    part_one: P1,
+    part_two: P2,
+) -> Result<()>
+where
+    I: BorrowedInput<'static>,
+    F: FnOnce(&I) -> S,
+    P1: FnOnce(&I, &S) -> O1,
+    O1: Solution,
+    P2: FnOnce(&I, &S) -> O2,
+    O2: Solution,
+{
+    let Some((options, cache)) = start::<I>()? else {
+        return Ok(());
+    };
+
+    // The input lives for the rest of the run so parsed types can borrow from it
+    let contents = fs::read(&options.input).context("unable to open input file")?;
+    let contents = String::from_utf8(contents).context("input is not valid UTF-8")?;
+    let input = I::parse_str(normalize_owned(contents, false).leak())?;
+
+    let start = Instant::now();
+    let state = prepare(&input);
+    if report_prepare {
+        println!("Prepared in {} seconds", start.elapsed().as_secs_f32());
+    }
+
+    let answers = [
+        run_part("one", || part_one(&input, &state)),
+        run_part("two", || part_two(&input, &state)),
+    ];
+
+    if let Some(cache) = cache {
+        cache.store(&answers)?;
+    }
+
+    Ok(())
+}
+
 pub fn solve<I, P1, O1, P2, O2>(part_one: P1, part_two: P2) -> Result<()>
 where
-    I: Input,
+    I: BorrowedInput<'static>,
     P1: FnOnce(&I) -> O1,
-    O1: Display,
+    O1: Solution,
     P2: FnOnce(&I) -> O2,
-    O2: Display,
+    O2: Solution,
 {
-    let path = args()
-        .nth(1)
-        .expect("expected input path as first argument");
-    let file = File::open(path).expect("unable to open input file");
-    let input = I::parse_reader(BufReader::new(file))?;
+    run(
+        |_| (),
+        false,
+        |input, _| part_one(input),
+        |input, _| part_two(input),
+    )
+}
 
-    let start = Instant::now();
-    let solution = part_one(&input);
-    println!(
-        "Solved part one in {} seconds",
-        start.elapsed().as_secs_f32()
-    );
-    println!("{solution}");
+pub fn solve_prepared<I, S, F, P1, O1, P2, O2>(prepare: F, part_one: P1, part_two: P2) -> Result<()>
+where
+    I: BorrowedInput<'static>,
+    F: FnOnce(&I) -> S,
+    P1: FnOnce(&I, &S) -> O1,
+    O1: Solution,
+    P2: FnOnce(&I, &S) -> O2,
+    O2: Solution,
+{
+    run(prepare, true, part_one, part_two)
+}
 
-    let start = Instant::now();
-    let solution = part_two(&input);
-    println!(
-        "Solved part two in {} seconds",
-        start.elapsed().as_secs_f32()
-    );
-    println!("{solution}");
+/// Like `solve`, but each part reads and parses the input file line by line as it iterates, so
+/// memory use does not grow with the input
+pub fn solve_streaming<T, P1, O1, P2, O2>(part_one: P1, part_two: P2) -> Result<()>
+where
+    T: FromStr,
+    Error: From<T::Err>,
+    P1: FnOnce(&mut Stream<T>) -> O1,
+    O1: Solution,
+    P2: FnOnce(&mut Stream<T>) -> O2,
+    O2: Solution,
+{
+    // Fuzzing parses the whole input like `Lines<T>` would, since that is what `Stream` matches
+    let Some((options, cache)) = start::<Lines<T>>()? else {
+        return Ok(());
+    };
+
+    // A parse error invalidates the answer, so only print once the stream is known to be clean
+    let mut stream = Stream::new(open_input(&options)?);
+    let one = time_part("one", || part_one(&mut stream));
+    stream.finish()?;
+    one.print(false);
+    let mut stream = Stream::new(open_input(&options)?);
+    let two = time_part("two", || part_two(&mut stream));
+    stream.finish()?;
+    two.print(false);
+
+    if let Some(cache) = cache {
+        cache.store(&[one, two])?;
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grid_render_round_trips() {
+        for input in ["#.#\n...\n", "\u{feff}ab \r\ncd \r\n\r\n", "é \n ü"] {
+            let grid = Grid::<char>::parse_reader(input.as_bytes()).unwrap();
+            assert_eq!(grid.to_string(), normalize(input, false));
+        }
+
+        let grid = Grid::<u8>::parse_reader(&b"ab\ncd\n"[..]).unwrap();
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+    }
+
+    #[test]
+    fn grid_render_maps_elements() {
+        let grid = Grid::from_elements(2, 2, vec![true, false, false, true]);
+        assert_eq!(grid.render(|&on| if on { '#' } else { '.' }), "#.\n.#\n");
+    }
+
+    #[test]
+    fn step_stops_at_each_edge() {
+        let grid = Grid::<u8>::default(3, 2);
+        let corner = Point2::new(0, 0);
+        let opposite = Point2::new(2, 1);
+        assert_eq!(grid.step(corner, Direction::North), None);
+        assert_eq!(grid.step(corner, Direction::West), None);
+        assert_eq!(grid.step(opposite, Direction::South), None);
+        assert_eq!(grid.step(opposite, Direction::East), None);
+        assert_eq!(
+            grid.step(corner, Direction::SouthEast),
+            Some(opposite - Vec2::new(1, 0))
+        );
+        assert_eq!(grid.step(corner, Vec2::new(2, 1)), Some(opposite));
+        assert_eq!(grid.neighbors8(corner).count(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "(3, 0) is outside a 3x2 grid")]
+    fn index_out_of_bounds_panics() {
+        let grid = Grid::<u8>::default(3, 2);
+        let _ = grid[Point2::new(3, 0)];
+    }
+}
@@ -0,0 +1,64 @@
+use std::{borrow::Cow, io::BufRead};
+
+use crate::{Input, Result};
+
+pub(crate) const BOM: &str = "\u{feff}";
+
+/// Strips a leading byte order mark, converts CRLF line endings to LF and ends non-empty input
+/// with exactly one newline, dropping any trailing blank lines. With `trim` set, trailing
+/// whitespace is also removed from every line, so whitespace-only lines become blank.
+pub fn normalize(contents: &str, trim: bool) -> Cow<'_, str> {
+    let contents = contents.strip_prefix(BOM).unwrap_or(contents);
+    let body = if trim {
+        contents.trim_end()
+    } else {
+        contents.trim_end_matches(['\r', '\n'])
+    };
+    if body.is_empty() {
+        return Cow::Borrowed("");
+    }
+
+    let clean = !body.contains('\r')
+        && (!trim || !body.split('\n').any(|l| l.ends_with(char::is_whitespace)));
+    if clean && &contents[body.len()..] == "\n" {
+        return Cow::Borrowed(contents);
+    }
+
+    let mut normalized = String::with_capacity(body.len() + 1);
+    for line in body.split('\n') {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        normalized.push_str(if trim { line.trim_end() } else { line });
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+pub fn read_normalized<R: BufRead>(mut reader: R, trim: bool) -> Result<String> {
+    let mut contents = String::new();
+    reader.read_to_string(&mut contents)?;
+    Ok(normalize_owned(contents, trim))
+}
+
+/// Trims trailing whitespace from every line before parsing `I`, for inputs whose lines may be
+/// padded. Built-in inputs otherwise keep it, so that `I` sees every column.
+pub struct Trimmed<I> {
+    pub input: I,
+}
+
+impl<I: Input> Input for Trimmed<I> {
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, true)?;
+        Ok(Self {
+            input: I::parse_reader(contents.as_bytes())?,
+        })
+    }
+}
+
+/// Like `normalize`, but reuses `contents` when it is already normalized so at most one buffer
+/// outlives the call
+pub(crate) fn normalize_owned(contents: String, trim: bool) -> String {
+    match normalize(&contents, trim) {
+        Cow::Borrowed(normalized) if normalized.len() == contents.len() => contents,
+        normalized => normalized.into_owned(),
+    }
+}
@@ -0,0 +1,56 @@
+use std::env::args;
+
+use crate::{Result, bail};
+
+pub struct Options {
+    pub input: String,
+    pub watch: bool,
+    pub no_cache: bool,
+    pub inspect: bool,
+    pub fuzz: bool,
+}
+
+impl Options {
+    pub fn from_args() -> Result<Self> {
+        let mut input = None;
+        let mut watch = false;
+        let mut no_cache = false;
+        let mut inspect = false;
+        let mut fuzz = false;
+
+        for arg in args().skip(1) {
+            match arg.as_str() {
+                "--watch" => watch = true,
+                "--no-cache" => no_cache = true,
+                "--inspect" => inspect = true,
+                "--fuzz" => fuzz = true,
+                flag if flag.starts_with("--") => bail!("unknown flag '{flag}'"),
+                _ if input.is_some() => bail!("unexpected argument '{arg}'"),
+                _ => input = Some(arg),
+            }
+        }
+
+        let Some(input) = input else {
+            bail!("expected input path as first argument");
+        };
+
+        Ok(Self {
+            input,
+            watch,
+            no_cache,
+            inspect,
+            fuzz,
+        })
+    }
+
+    pub fn child_args(&self) -> Vec<String> {
+        let mut args = vec![self.input.clone()];
+        if self.no_cache {
+            args.push("--no-cache".to_string());
+        }
+        if self.inspect {
+            args.push("--inspect".to_string());
+        }
+        args
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::{Error, Pair};
+
+/// A position on a grid, with `y` growing downwards
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: usize,
+    pub y: usize,
+}
+
+/// A signed offset between two `Point2`s
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Vec2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point2 {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns `None` if either coordinate would become negative or overflow
+    pub fn checked_add(self, offset: impl Into<Vec2>) -> Option<Self> {
+        let offset = offset.into();
+        Some(Self {
+            x: self.x.checked_add_signed(offset.x)?,
+            y: self.y.checked_add_signed(offset.y)?,
+        })
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> usize {
+        usize::max(self.x.abs_diff(other.x), self.y.abs_diff(other.y))
+    }
+}
+
+impl Vec2 {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    pub fn chebyshev(self) -> usize {
+        usize::max(self.x.unsigned_abs(), self.y.unsigned_abs())
+    }
+}
+
+impl From<(usize, usize)> for Point2 {
+    fn from((x, y): (usize, usize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point2> for (usize, usize) {
+    fn from(point: Point2) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl FromStr for Point2 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Pair(x, y) = s.parse::<Pair<_, _>>()?;
+        Ok(Self { x, y })
+    }
+}
+
+impl fmt::Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+// Points are unsigned, so the offset between two of them may not fit. Coordinates are assumed
+// to stay below `isize::MAX`.
+impl Sub for Point2 {
+    type Output = Vec2;
+
+    fn sub(self, other: Self) -> Vec2 {
+        Vec2 {
+            x: self.x as isize - other.x as isize,
+            y: self.y as isize - other.y as isize,
+        }
+    }
+}
+
+// Moving a point below zero is a bug like integer overflow, so it panics. Use `checked_add` or
+// `Grid::step` where a point may leave the grid.
+impl Add<Vec2> for Point2 {
+    type Output = Self;
+
+    #[track_caller]
+    fn add(self, offset: Vec2) -> Self {
+        match self.checked_add(offset) {
+            Some(point) => point,
+            None => panic!("{self} + {offset:?} is out of range"),
+        }
+    }
+}
+
+impl AddAssign<Vec2> for Point2 {
+    #[track_caller]
+    fn add_assign(&mut self, offset: Vec2) {
+        *self = *self + offset;
+    }
+}
+
+impl Sub<Vec2> for Point2 {
+    type Output = Self;
+
+    #[track_caller]
+    fn sub(self, offset: Vec2) -> Self {
+        self + -offset
+    }
+}
+
+impl SubAssign<Vec2> for Point2 {
+    #[track_caller]
+    fn sub_assign(&mut self, offset: Vec2) {
+        *self = *self - offset;
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl AddAssign for Vec2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl SubAssign for Vec2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Self;
+
+    fn mul(self, scale: isize) -> Self {
+        Self {
+            x: self.x * scale,
+            y: self.y * scale,
+        }
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+/// Compass directions in clockwise order, with north towards row 0
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Rotates clockwise by `eighths` of a turn, counterclockwise when negative
+    pub fn rotate(self, eighths: isize) -> Self {
+        Self::ALL[(self as isize + eighths).rem_euclid(8) as usize]
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(-2)
+    }
+
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn offset(self) -> Vec2 {
+        let (x, y) = match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        };
+        Vec2 { x, y }
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_arithmetic() {
+        let mut point = Point2::new(3, 4);
+        assert_eq!(point + Vec2::new(-3, 2), Point2::new(0, 6));
+        assert_eq!(point - Vec2::new(1, 4), Point2::new(2, 0));
+        assert_eq!(Point2::new(0, 6) - point, Vec2::new(-3, 2));
+
+        point += Direction::NorthWest.into();
+        assert_eq!(point, Point2::new(2, 3));
+        point -= Vec2::new(2, 3) * 1;
+        assert_eq!(point, Point2::new(0, 0));
+        assert_eq!(point.checked_add(Direction::West), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "0,0 + Vec2 { x: 0, y: -1 } is out of range")]
+    fn point_below_zero_panics() {
+        let _ = Point2::new(0, 0) + Direction::North.offset();
+    }
+
+    #[test]
+    fn rotation_wraps() {
+        assert_eq!(Direction::NorthWest.rotate(1), Direction::North);
+        assert_eq!(Direction::North.rotate(-1), Direction::NorthWest);
+        assert_eq!(Direction::East.rotate(-10), Direction::North);
+        assert_eq!(Direction::South.rotate(17), Direction::SouthWest);
+        for direction in Direction::ALL {
+            assert_eq!(direction.rotate(8), direction);
+            assert_eq!(direction.rotate(-8), direction);
+        }
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::NorthEast.turn_right(), Direction::SouthEast);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+            assert_eq!(direction.is_diagonal(), direction.offset().manhattan() == 2);
+        }
+    }
+}
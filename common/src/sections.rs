@@ -0,0 +1,183 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::{Context as _, Error, Input, ParseError, Result, Snippet, bail, read_normalized};
+
+pub struct Section {
+    pub line: usize,
+    pub text: String,
+}
+
+impl Section {
+    pub fn parse<T: Input>(&self) -> Result<T> {
+        T::parse_reader(self.text.as_bytes())
+            .map_err(|mut e| {
+                if let Some(snippet) = e.downcast_mut::<Snippet>() {
+                    snippet.offset(self.line - 1);
+                }
+                if let Some(error) = e.downcast_mut::<ParseError>() {
+                    error.offset(self.line - 1);
+                }
+                e
+            })
+            .with_context(|| format!("in section starting at line {}", self.line))
+    }
+}
+
+pub fn split_sections<R: BufRead>(reader: R) -> Result<Vec<Section>> {
+    let mut sections = Vec::new();
+    let mut current = None::<Section>;
+
+    for (i, line) in read_normalized(reader, false)?.lines().enumerate() {
+        if line.is_empty() {
+            sections.extend(current.take());
+        } else {
+            let section = current.get_or_insert_with(|| Section {
+                line: i + 1,
+                text: String::new(),
+            });
+            section.text.push_str(line);
+            section.text.push('\n');
+        }
+    }
+    sections.extend(current);
+
+    Ok(sections)
+}
+
+pub struct SectionCursor {
+    lines: Vec<String>,
+    pos: usize,
+}
+
+impl SectionCursor {
+    pub fn new<R: BufRead>(reader: R) -> Result<Self> {
+        Ok(Self {
+            lines: read_normalized(reader, false)?
+                .lines()
+                .map(str::to_string)
+                .collect(),
+            pos: 0,
+        })
+    }
+
+    fn skip_blank(&mut self) {
+        while self.lines.get(self.pos).is_some_and(|l| l.is_empty()) {
+            self.pos += 1;
+        }
+    }
+
+    fn take(&mut self, name: &str, end: usize) -> Result<Section> {
+        if self.pos >= end {
+            return Err(ParseError::MissingSection {
+                line: self.lines.len(),
+                name: name.to_string(),
+            }
+            .into());
+        }
+
+        let mut text = String::new();
+        for line in &self.lines[self.pos..end] {
+            text.push_str(line);
+            text.push('\n');
+        }
+        let section = Section {
+            line: self.pos + 1,
+            text,
+        };
+        self.pos = end;
+
+        Ok(section)
+    }
+
+    pub fn section<T: Input>(&mut self, name: &str) -> Result<T> {
+        self.skip_blank();
+        let end = self.lines[self.pos..]
+            .iter()
+            .position(String::is_empty)
+            .map_or(self.lines.len(), |i| self.pos + i);
+        self.take(name, end)?
+            .parse()
+            .with_context(|| format!("invalid {name}"))
+    }
+
+    pub fn line<T>(&mut self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        Error: From<T::Err>,
+    {
+        self.skip_blank();
+        let line = self.pos + 1;
+        let section = self.take(name, usize::min(line, self.lines.len()))?;
+        let text = section.text.trim_end_matches('\n');
+        text.parse()
+            .map_err(Error::from)
+            .with_context(|| Snippet::line(line, text))
+            .with_context(|| format!("invalid {name}"))
+    }
+
+    pub fn rest<T: Input>(&mut self, name: &str) -> Result<T> {
+        self.skip_blank();
+        self.take(name, self.lines.len())?
+            .parse()
+            .with_context(|| format!("invalid {name}"))
+    }
+
+    pub fn finish(mut self) -> Result<()> {
+        self.skip_blank();
+        if self.pos < self.lines.len() {
+            bail!("unexpected trailing input at line {}", self.pos + 1);
+        }
+        Ok(())
+    }
+}
+
+pub struct Sections<T> {
+    pub sections: Vec<T>,
+}
+
+impl<T: Input> Input for Sections<T> {
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        Ok(Self {
+            sections: split_sections(reader)?
+                .iter()
+                .map(Section::parse)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+fn expect_sections<const N: usize, R: BufRead>(reader: R) -> Result<[Section; N]> {
+    let sections = split_sections(reader)?;
+    if sections.len() > N {
+        bail!(
+            "expected {N} sections but found {}, extra section starts at line {}",
+            sections.len(),
+            sections[N].line,
+        );
+    }
+    let found = sections.len();
+    let line = sections
+        .last()
+        .map_or(0, |section| section.line + section.text.lines().count() - 1);
+    sections.try_into().map_err(|_| {
+        ParseError::MissingSection {
+            line,
+            name: format!("section {} of {N}", found + 1),
+        }
+        .into()
+    })
+}
+
+impl<A: Input, B: Input> Input for (A, B) {
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let [a, b] = expect_sections(reader)?;
+        Ok((a.parse()?, b.parse()?))
+    }
+}
+
+impl<A: Input, B: Input, C: Input> Input for (A, B, C) {
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let [a, b, c] = expect_sections(reader)?;
+        Ok((a.parse()?, b.parse()?, c.parse()?))
+    }
+}
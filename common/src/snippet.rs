@@ -0,0 +1,73 @@
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Snippet {
+    line: usize,
+    column: usize,
+    len: usize,
+    text: String,
+}
+
+impl Snippet {
+    /// `line` is 1-based, `column` and `len` count characters from the start of `text`
+    pub fn span(line: usize, text: &str, column: usize, len: usize) -> Self {
+        Self {
+            line,
+            column: column + 1,
+            len: len.max(1),
+            text: text.to_string(),
+        }
+    }
+
+    pub fn line(line: usize, text: &str) -> Self {
+        Self::span(line, text, 0, text.chars().count())
+    }
+
+    /// Locates the byte range `start..end` of `contents`, clipping it to the line it starts on
+    pub fn locate(contents: &str, start: usize, end: usize) -> Self {
+        let line_start = contents[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = contents[start..]
+            .find('\n')
+            .map_or(contents.len(), |i| start + i);
+        let text = contents[line_start..line_end].trim_end_matches('\r');
+        let line = contents[..start].matches('\n').count() + 1;
+        let column = contents[line_start..start].chars().count();
+        let len = contents[start..usize::min(end, line_end)].chars().count();
+
+        Self::span(line, text, column, len)
+    }
+
+    /// Moves the snippet down by `lines`, for text parsed out of the middle of a larger input
+    pub fn offset(&mut self, lines: usize) {
+        self.line += lines;
+    }
+
+    pub fn line_number(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for Snippet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        writeln!(f, "at line {}, column {}:", self.line, self.column)?;
+        writeln!(f, " {number} | {}", self.text)?;
+        write!(
+            f,
+            " {:pad$} | {:col$}{}",
+            "",
+            "",
+            "^".repeat(self.len),
+            pad = number.len(),
+            col = self.column - 1,
+        )
+    }
+}
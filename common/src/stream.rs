@@ -0,0 +1,94 @@
+use std::{fs::File, io::BufRead, io::BufReader, marker::PhantomData, str::FromStr};
+
+use crate::{Error, Result, normalize::BOM, parse_line};
+
+/// Parses one record per line as it is consumed, holding a single line in memory. Lines are
+/// normalized like `Lines<T>`, and trailing blank lines are skipped. Iteration stops at the first
+/// error, which the runner reports once the part returns.
+pub struct Stream<T, R = BufReader<File>> {
+    reader: R,
+    buffer: String,
+    line: usize,
+    pending_blank: usize,
+    pending: bool,
+    error: Option<Error>,
+    element: PhantomData<T>,
+}
+
+impl<T, R: BufRead> Stream<T, R> {
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buffer: String::new(),
+            line: 0,
+            pending_blank: 0,
+            pending: false,
+            error: None,
+            element: PhantomData,
+        }
+    }
+
+    /// Returns the error which ended iteration early, if any
+    pub fn finish(self) -> Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+
+    // Reads the next non-blank line into `buffer`, counting blank lines skipped on the way
+    fn read_line(&mut self) -> Result<bool> {
+        loop {
+            self.buffer.clear();
+            if self.reader.read_line(&mut self.buffer)? == 0 {
+                return Ok(false);
+            }
+            self.line += 1;
+
+            if self.line == 1 && self.buffer.starts_with(BOM) {
+                self.buffer.drain(..BOM.len());
+            }
+            if self.buffer.ends_with('\n') {
+                self.buffer.pop();
+                if self.buffer.ends_with('\r') {
+                    self.buffer.pop();
+                }
+            }
+
+            if !self.buffer.is_empty() {
+                return Ok(true);
+            }
+            self.pending_blank += 1;
+        }
+    }
+}
+
+impl<T: FromStr, R: BufRead> Iterator for Stream<T, R>
+where
+    Error: From<T::Err>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        // Blank lines are only parsed once a later line shows they are not trailing
+        let result = if self.pending_blank > 0 && self.pending {
+            self.pending_blank -= 1;
+            parse_line(self.line - self.pending_blank - 1, "")
+        } else if self.pending {
+            self.pending = false;
+            parse_line(self.line, &self.buffer)
+        } else {
+            match self.read_line() {
+                Ok(true) => {
+                    self.pending = true;
+                    return self.next();
+                }
+                Ok(false) => return None,
+                Err(e) => Err(e),
+            }
+        };
+
+        result.map_err(|e| self.error = Some(e)).ok()
+    }
+}
@@ -0,0 +1,187 @@
+use std::ops::Range;
+
+use crate::{BorrowedInput, Result, bail};
+
+/// A fixed-width block of text split into groups of columns separated by columns which are blank
+/// in every row. Rows borrow from the input, short rows read as if padded with spaces.
+pub struct Table<'a> {
+    rows: Vec<&'a str>,
+    width: usize,
+    groups: Vec<Range<usize>>,
+    ascii: bool,
+}
+
+impl<'a> Table<'a> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The row with trailing whitespace removed, so it may be shorter than `width`
+    pub fn row(&self, y: usize) -> &'a str {
+        self.rows[y]
+    }
+
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    pub fn group(&self, index: usize) -> Group<'_, 'a> {
+        Group {
+            table: self,
+            columns: self.groups[index].clone(),
+            rows: 0..self.height(),
+        }
+    }
+
+    pub fn groups(&self) -> impl Iterator<Item = Group<'_, 'a>> {
+        (0..self.len()).map(|i| self.group(i))
+    }
+
+    // Columns count chars, so byte offsets only need searching for when a row is not ASCII
+    fn slice(&self, y: usize, columns: Range<usize>) -> &'a str {
+        let row = self.rows[y];
+        let byte = |x: usize| {
+            if self.ascii {
+                x.min(row.len())
+            } else {
+                row.char_indices().nth(x).map_or(row.len(), |(i, _)| i)
+            }
+        };
+        &row[byte(columns.start)..byte(columns.end)]
+    }
+
+    // Positions past the end of a row read as the spaces it was trimmed of
+    fn char_at(&self, x: usize, y: usize) -> char {
+        let row = self.rows[y];
+        if self.ascii {
+            row.as_bytes().get(x).map_or(' ', |&b| b as char)
+        } else {
+            row.chars().nth(x).unwrap_or(' ')
+        }
+    }
+}
+
+impl<'a> BorrowedInput<'a> for Table<'a> {
+    fn parse_str(contents: &'a str) -> Result<Self> {
+        let mut rows = contents.lines().map(str::trim_end).collect::<Vec<_>>();
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        let ascii = rows.iter().all(|row| row.is_ascii());
+        let Some(width) = rows
+            .iter()
+            .map(|row| {
+                if ascii {
+                    row.len()
+                } else {
+                    row.chars().count()
+                }
+            })
+            .max()
+        else {
+            bail!("empty table input");
+        };
+
+        // A column is blank when every row is either too short to reach it or has a space there
+        let mut filled = vec![false; width];
+        for row in &rows {
+            for (x, c) in row.chars().enumerate() {
+                filled[x] |= c != ' ';
+            }
+        }
+
+        let mut groups = Vec::new();
+        let mut start = None;
+        for (x, filled) in filled.into_iter().chain([false]).enumerate() {
+            match (start, filled) {
+                (None, true) => start = Some(x),
+                (Some(s), false) => {
+                    groups.push(s..x);
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Self {
+            rows,
+            width,
+            groups,
+            ascii,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Group<'t, 'a> {
+    table: &'t Table<'a>,
+    columns: Range<usize>,
+    rows: Range<usize>,
+}
+
+impl<'t, 'a> Group<'t, 'a> {
+    /// The column of the table where this group starts
+    pub fn left(&self) -> usize {
+        self.columns.start
+    }
+
+    /// The row of the table where this group starts
+    pub fn top(&self) -> usize {
+        self.rows.start
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<char> {
+        if x < self.width() && y < self.height() {
+            Some(self.table.char_at(self.left() + x, self.top() + y))
+        } else {
+            None
+        }
+    }
+
+    /// Narrows the group to a range of its rows
+    pub fn row_range(&self, rows: Range<usize>) -> Self {
+        assert!(rows.start <= rows.end && rows.end <= self.height());
+        Group {
+            table: self.table,
+            columns: self.columns.clone(),
+            rows: self.rows.start + rows.start..self.rows.start + rows.end,
+        }
+    }
+
+    /// The group's part of a row, which is shorter than `width` when the row is
+    pub fn row(&self, y: usize) -> &'a str {
+        self.table.slice(self.rows.start + y, self.columns.clone())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &'a str> + '_ {
+        (0..self.height()).map(|y| self.row(y))
+    }
+
+    /// The chars of a column from top to bottom, with spaces for rows too short to reach it
+    pub fn column(&self, x: usize) -> impl Iterator<Item = char> + '_ {
+        assert!(x < self.width());
+        self.rows
+            .clone()
+            .map(move |y| self.table.char_at(self.left() + x, y))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = char> + '_> + '_ {
+        (0..self.width()).map(|x| self.column(x))
+    }
+}
@@ -0,0 +1,72 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::{
+    Error, Input, ParseError, Result, Snippet, anyhow, parse_element, parse_elements,
+    read_normalized,
+};
+
+/// Lines split into tokens on any run of whitespace
+pub struct Tokens<T> {
+    pub lines: Vec<Vec<T>>,
+}
+
+impl<T: FromStr> Input for Tokens<T>
+where
+    Error: From<T::Err>,
+{
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, false)?;
+
+        Ok(Self {
+            lines: contents
+                .lines()
+                .map(|line| parse_elements(&contents, line.split_whitespace()))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// Splits lines of the form `key: a b c` into the key and its whitespace separated values. Keys
+/// are trimmed and must not be empty, values may be.
+pub fn keyed_lines(contents: &str) -> impl Iterator<Item = Result<(&str, Vec<&str>)>> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match line.split_once(':') {
+            Some((key, values)) if !key.trim().is_empty() => {
+                Ok((key.trim(), values.split_whitespace().collect()))
+            }
+            _ => Err(anyhow!("expected 'key: values'")
+                .context(ParseError::InvalidToken {
+                    line: i + 1,
+                    column: 1,
+                    token: line.to_string(),
+                })
+                .context(Snippet::line(i + 1, line))),
+        })
+}
+
+pub struct KeyedLines<K, V> {
+    pub lines: Vec<(K, Vec<V>)>,
+}
+
+impl<K: FromStr, V: FromStr> Input for KeyedLines<K, V>
+where
+    Error: From<K::Err> + From<V::Err>,
+{
+    fn parse_reader<R: BufRead>(reader: R) -> Result<Self> {
+        let contents = read_normalized(reader, false)?;
+
+        Ok(Self {
+            lines: keyed_lines(&contents)
+                .map(|line| {
+                    let (key, values) = line?;
+                    Ok((
+                        parse_element(&contents, key)?,
+                        parse_elements(&contents, values.into_iter())?,
+                    ))
+                })
+                .collect::<Result<_>>()?,
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{Context as _, Result, options::Options};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+#[derive(Clone, Copy, PartialEq)]
+struct Stamp {
+    latest: Option<SystemTime>,
+    files: usize,
+}
+
+fn stamp(paths: &[PathBuf]) -> Result<Stamp> {
+    fn visit(path: &Path, stamp: &mut Stamp) -> Result<()> {
+        let Ok(metadata) = fs::metadata(path) else {
+            // The file may be mid-save, pick it up on the next poll
+            return Ok(());
+        };
+
+        if metadata.is_dir() {
+            for entry in fs::read_dir(path)? {
+                visit(&entry?.path(), stamp)?;
+            }
+        } else {
+            let modified = metadata.modified()?;
+            stamp.latest = Some(stamp.latest.map_or(modified, |l| l.max(modified)));
+            stamp.files += 1;
+        }
+
+        Ok(())
+    }
+
+    let mut result = Stamp {
+        latest: None,
+        files: 0,
+    };
+    for path in paths {
+        visit(path, &mut result)?;
+    }
+    Ok(result)
+}
+
+type Answers = Vec<(String, Option<String>)>;
+
+fn parse_answers(output: &str) -> Answers {
+    let mut answers = Answers::new();
+    for line in output.lines() {
+        if let Some(header) = line.strip_prefix("Solved part ")
+            && let Some((name, _)) = header.split_once(" in ")
+        {
+            answers.push((name.to_string(), Some(String::new())));
+        } else if let Some(name) = line
+            .strip_prefix("Part ")
+            .and_then(|l| l.strip_suffix(" is not implemented"))
+        {
+            answers.push((name.to_string(), None));
+        } else if let Some((_, Some(answer))) = answers.last_mut() {
+            if !answer.is_empty() {
+                answer.push('\n');
+            }
+            answer.push_str(line);
+        }
+    }
+    answers
+}
+
+fn print_diff(previous: &Answers, current: &Answers) {
+    println!("Changes since previous run:");
+    for (name, answer) in current {
+        let old = previous.iter().find(|(n, _)| n == name).map(|(_, a)| a);
+        match (old, answer) {
+            (Some(old), new) if old == new => println!("  part {name}: unchanged"),
+            (Some(Some(old)), Some(new)) => println!("  part {name}: {old} -> {new}"),
+            (_, Some(new)) => println!("  part {name}: new answer {new}"),
+            (_, None) => println!("  part {name}: missing"),
+        }
+    }
+    for (name, _) in previous {
+        if !current.iter().any(|(n, _)| n == name) {
+            println!("  part {name}: missing");
+        }
+    }
+}
+
+pub fn watch(options: &Options) -> Result<()> {
+    let exe = env::current_exe().context("unable to locate current executable")?;
+    let package = match env::var("CARGO_PKG_NAME") {
+        Ok(package) => package,
+        Err(_) => exe
+            .file_stem()
+            .context("executable has no file name")?
+            .to_string_lossy()
+            .into_owned(),
+    };
+    let manifest_dir = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .context("watch mode must be started through `cargo run`")?;
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+
+    let paths = vec![
+        manifest_dir.join("src"),
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../common_derive/src"),
+        PathBuf::from(&options.input),
+    ];
+
+    let mut last_stamp = None;
+    let mut previous = None;
+    loop {
+        let current_stamp = stamp(&paths)?;
+        if last_stamp != Some(current_stamp) {
+            last_stamp = Some(current_stamp);
+
+            let mut build = Command::new(&cargo);
+            build.args(["build", "-p", &package]);
+            if !cfg!(debug_assertions) {
+                build.arg("--release");
+            }
+
+            if build
+                .status()
+                .context("failed to run cargo build")?
+                .success()
+            {
+                let output = Command::new(&exe)
+                    .args(options.child_args())
+                    .stderr(Stdio::inherit())
+                    .output()
+                    .context("failed to run solution")?;
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                print!("{stdout}");
+
+                if output.status.success() {
+                    let answers = parse_answers(&stdout);
+                    if let Some(previous) = &previous {
+                        print_diff(previous, &answers);
+                    }
+                    previous = Some(answers);
+                } else {
+                    println!("Solution exited with {}", output.status);
+                }
+            }
+
+            println!("Watching for changes...");
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
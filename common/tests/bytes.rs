@@ -0,0 +1,83 @@
+use common::{Input, IntegerLines, Lines, ParseError, integers};
+
+#[test]
+fn integer_lines_match_lines() {
+    for input in [
+        "1 2\n3\n\n\n",
+        "\u{feff}1 2\r\n3\r\n\r\n",
+        "1 2\n3",
+        "1 2\n\n3\n  \n",
+    ] {
+        let integers = IntegerLines::<u32>::parse_reader(input.as_bytes()).unwrap();
+        let lines = Lines::<String>::parse_reader(input.as_bytes()).unwrap();
+        assert_eq!(integers.len(), lines.lines.len(), "{input:?}");
+    }
+
+    let integers = IntegerLines::<u32>::parse_reader(&b"1 2\n3\n\n\n"[..]).unwrap();
+    assert_eq!(integers.iter().collect::<Vec<_>>(), [&[1, 2][..], &[3]]);
+}
+
+#[test]
+fn empty_integer_lines() {
+    for input in ["", "\n\n", "\u{feff}"] {
+        assert!(
+            IntegerLines::<u32>::parse_reader(input.as_bytes())
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
+
+fn parse_error<T: common::Integer>(input: &str) -> ParseError {
+    let Err(error) = IntegerLines::<T>::parse_reader(input.as_bytes()) else {
+        panic!("expected {input:?} to be rejected");
+    };
+    error.downcast_ref::<ParseError>().unwrap().clone()
+}
+
+#[test]
+fn integer_lines_reject_out_of_range() {
+    assert_eq!(
+        parse_error::<u8>("1 2\n1 300 2\n"),
+        ParseError::OutOfRange {
+            line: 2,
+            column: 3,
+            token: "300".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error::<i8>("x=-129\n"),
+        ParseError::OutOfRange {
+            line: 1,
+            column: 3,
+            token: "-129".to_string(),
+        }
+    );
+}
+
+#[test]
+fn integer_lines_reject_negative_unsigned() {
+    assert_eq!(
+        parse_error::<u32>("-5,7\n"),
+        ParseError::InvalidToken {
+            line: 1,
+            column: 1,
+            token: "-5".to_string(),
+        }
+    );
+
+    let ranges = IntegerLines::<u32>::parse_reader(&b"3-5\n"[..]).unwrap();
+    assert_eq!(ranges.line(0), [3, 5]);
+    let signed = IntegerLines::<i32>::parse_reader(&b"-5,7 x-2\n"[..]).unwrap();
+    assert_eq!(signed.line(0), [-5, 7, -2]);
+}
+
+#[test]
+fn integers_are_lenient() {
+    assert_eq!(integers::<u8>(b"1 300 2").collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(integers::<u32>(b"-5,7").collect::<Vec<_>>(), [5, 7]);
+    assert_eq!(
+        integers::<i32>(b"-5,7 3-4").collect::<Vec<_>>(),
+        [-5, 7, 3, 4]
+    );
+}
@@ -0,0 +1,196 @@
+use common::{Input, Lines, Parse, ParseError};
+
+#[derive(Debug, PartialEq, Parse)]
+#[parse("p={x},{y}")]
+struct Position {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq, Parse)]
+#[parse("[{name}] {values: } {{{weights:,}}}")]
+struct Record {
+    name: String,
+    values: Vec<u32>,
+    weights: Vec<u32>,
+}
+
+#[derive(Debug, PartialEq, Parse)]
+enum Instruction {
+    #[parse("+{0}")]
+    Add(u32),
+    #[parse("++")]
+    Increment,
+    #[parse("swap {0} with {1}")]
+    Swap(usize, usize),
+    #[parse("{0}")]
+    Push(i64),
+}
+
+#[test]
+fn literal_prefix() {
+    assert_eq!(
+        "p=3,-4".parse::<Position>().unwrap(),
+        Position { x: 3, y: -4 }
+    );
+    assert_eq!(
+        "q=3,-4".parse::<Position>().unwrap_err().to_string(),
+        "expected 'p=' at 'q=3,-4'"
+    );
+}
+
+#[test]
+fn repeated_separated_fields() {
+    assert_eq!(
+        "[abc] 1 2 3 {4,5}".parse::<Record>().unwrap(),
+        Record {
+            name: "abc".to_string(),
+            values: vec![1, 2, 3],
+            weights: vec![4, 5],
+        }
+    );
+    let error = "[abc] 1 x 3 {4,5}".parse::<Record>().unwrap_err();
+    assert_eq!(error.to_string(), "invalid values element 'x'");
+}
+
+#[test]
+fn empty_separated_fields() {
+    let record = |values: &[u32], weights: &[u32]| Record {
+        name: "abc".to_string(),
+        values: values.to_vec(),
+        weights: weights.to_vec(),
+    };
+    assert_eq!(
+        "[abc] {4,5}".parse::<Record>().unwrap(),
+        record(&[], &[4, 5])
+    );
+    assert_eq!(
+        "[abc]  {4,5}".parse::<Record>().unwrap(),
+        record(&[], &[4, 5])
+    );
+    assert_eq!(
+        "[abc] 1 2 {}".parse::<Record>().unwrap(),
+        record(&[1, 2], &[])
+    );
+    assert_eq!("[abc] {}".parse::<Record>().unwrap(), record(&[], &[]));
+    assert_eq!(
+        "[abc] 1 {,}".parse::<Record>().unwrap_err().to_string(),
+        "invalid weights element ''"
+    );
+}
+
+#[test]
+fn longest_prefix_dispatch() {
+    assert_eq!("++".parse::<Instruction>().unwrap(), Instruction::Increment);
+    assert_eq!("+7".parse::<Instruction>().unwrap(), Instruction::Add(7));
+    assert_eq!(
+        "swap 1 with 2".parse::<Instruction>().unwrap(),
+        Instruction::Swap(1, 2)
+    );
+    assert_eq!("-5".parse::<Instruction>().unwrap(), Instruction::Push(-5));
+}
+
+#[test]
+fn trailing_input_rejected() {
+    assert_eq!(
+        "++x".parse::<Instruction>().unwrap_err().to_string(),
+        "unexpected trailing input 'x'"
+    );
+    assert_eq!(
+        "p=1,2,3".parse::<Position>().unwrap_err().to_string(),
+        "invalid y '2,3'"
+    );
+}
+
+#[test]
+fn derived_types_in_lines() {
+    let input = Lines::<Position>::parse_reader(&b"p=1,2\np=3,4\n"[..]).unwrap();
+    assert_eq!(
+        input.lines,
+        [Position { x: 1, y: 2 }, Position { x: 3, y: 4 }]
+    );
+}
+
+#[derive(Input)]
+struct Puzzle {
+    #[input(line)]
+    start: Position,
+    numbers: Lines<u32>,
+    #[input(rest)]
+    notes: Lines<String>,
+}
+
+#[derive(Input)]
+struct Pair {
+    first_half: Lines<u32>,
+    second_half: Lines<u32>,
+}
+
+#[test]
+fn input_field_kinds() {
+    let puzzle = Puzzle::parse_reader(&b"p=1,2\n\n3\n4\n\nfoo\n\nbar\n"[..]).unwrap();
+    assert_eq!(puzzle.start, Position { x: 1, y: 2 });
+    assert_eq!(puzzle.numbers.lines, [3, 4]);
+    assert_eq!(puzzle.notes.lines, ["foo", "", "bar"]);
+}
+
+#[test]
+fn input_skips_blank_lines_between_fields() {
+    let puzzle = Puzzle::parse_reader(&b"\n\np=1,2\n3\n\n\n\nfoo\n\n"[..]).unwrap();
+    assert_eq!(puzzle.start, Position { x: 1, y: 2 });
+    assert_eq!(puzzle.numbers.lines, [3]);
+    assert_eq!(puzzle.notes.lines, ["foo"]);
+
+    let pair = Pair::parse_reader(&b"1\n\n\n2\n3\n"[..]).unwrap();
+    assert_eq!(pair.first_half.lines, [1]);
+    assert_eq!(pair.second_half.lines, [2, 3]);
+}
+
+#[test]
+fn input_missing_section() {
+    let Err(error) = Pair::parse_reader(&b"1\n2\n\n"[..]) else {
+        panic!("expected a missing section");
+    };
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        Some(&ParseError::MissingSection {
+            line: 2,
+            name: "second half".to_string(),
+        })
+    );
+
+    let Err(error) = Puzzle::parse_reader(&b""[..]) else {
+        panic!("expected a missing line");
+    };
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        Some(&ParseError::MissingSection {
+            line: 0,
+            name: "start".to_string(),
+        })
+    );
+}
+
+#[test]
+fn input_rejects_trailing_sections() {
+    let Err(error) = Pair::parse_reader(&b"1\n\n2\n\n3\n"[..]) else {
+        panic!("expected trailing input to be rejected");
+    };
+    assert_eq!(error.to_string(), "unexpected trailing input at line 5");
+}
+
+#[test]
+fn input_field_errors_are_located() {
+    let Err(error) = Puzzle::parse_reader(&b"p=1,2\n\n3\nx\n"[..]) else {
+        panic!("expected an invalid number");
+    };
+    assert_eq!(error.to_string(), "invalid numbers");
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        Some(&ParseError::InvalidToken {
+            line: 4,
+            column: 1,
+            token: "x".to_string(),
+        })
+    );
+}
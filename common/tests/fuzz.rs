@@ -0,0 +1,40 @@
+use std::{io::BufRead, thread};
+
+use common::{Input, Lines, fuzz};
+
+struct Fragile;
+
+impl Input for Fragile {
+    fn parse_reader<R: BufRead>(reader: R) -> common::Result<Self> {
+        for line in reader.lines() {
+            assert!(!line?.contains('x'), "found an x");
+        }
+        Ok(Self)
+    }
+}
+
+#[test]
+fn robust_parsers_pass() {
+    fuzz::<Lines<u32>>("1\n2\n3\n", 500).unwrap();
+}
+
+#[test]
+fn panics_are_reported() {
+    let error = fuzz::<Fragile>("abc\ndef\n", 500).unwrap_err();
+    assert!(error.to_string().starts_with("parser panicked on "));
+}
+
+#[test]
+fn parallel_fuzzing() {
+    let threads = (0..4)
+        .map(|_| thread::spawn(|| fuzz::<Fragile>("abc\ndef\n", 500).is_err()))
+        .collect::<Vec<_>>();
+    assert!(threads.into_iter().all(|t| t.join().unwrap()));
+}
+
+#[test]
+#[should_panic(expected = "outside of fuzzing")]
+fn other_panics_still_unwind() {
+    fuzz::<Lines<u32>>("1\n", 10).unwrap();
+    panic!("outside of fuzzing");
+}
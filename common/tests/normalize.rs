@@ -0,0 +1,46 @@
+use common::{ByteGrid, Grid, Input, Lines, Stream, Trimmed, normalize};
+
+#[test]
+fn normalize_is_lossless_by_default() {
+    assert_eq!(normalize("\u{feff}a \r\nb\r\n\r\n\n", false), "a \nb\n");
+    assert_eq!(normalize("a \nb", false), "a \nb\n");
+    assert_eq!(normalize("a \n  \n", false), "a \n  \n");
+    assert_eq!(normalize("a \n  \n", true), "a\n");
+    assert_eq!(normalize("\n\n", false), "");
+}
+
+#[test]
+fn grids_keep_trailing_spaces() {
+    let grid = Grid::<char>::parse_reader(&b"#  \n## \n###\n"[..]).unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 3));
+    assert_eq!(grid.get(2, 0), Some(&' '));
+
+    let grid = Grid::<char>::parse_reader(&b"a \r\nb \r\n"[..]).unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+
+    let grid = ByteGrid::parse_reader(&b"a \r\nb \r\n\r\n"[..])
+        .unwrap()
+        .grid;
+    assert_eq!((grid.width(), grid.height()), (2, 2));
+    assert_eq!(grid.get(1, 1), Some(&b' '));
+}
+
+#[test]
+fn lines_keep_trailing_spaces() {
+    let input = "12 \n3  \n\n";
+    let lines = Lines::<String>::parse_reader(input.as_bytes()).unwrap();
+    assert_eq!(lines.lines, ["12 ", "3  "]);
+
+    let stream = Stream::<String, _>::new(input.as_bytes()).collect::<Vec<_>>();
+    assert_eq!(stream, lines.lines);
+}
+
+#[test]
+fn trimming_is_opt_in() {
+    let trimmed = Trimmed::<Grid<char>>::parse_reader(&b"a \nb\n"[..]).unwrap();
+    assert_eq!(trimmed.input.width(), 1);
+
+    let trimmed = Trimmed::<Lines<u32>>::parse_reader(&b"1 \n2\t\n \n"[..]).unwrap();
+    assert_eq!(trimmed.input.lines, [1, 2]);
+    assert!(Lines::<u32>::parse_reader(&b"1 \n2\n"[..]).is_err());
+}
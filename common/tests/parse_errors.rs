@@ -0,0 +1,132 @@
+use common::{ByteGrid, Error, Grid, Input, Lines, List, ParseError, SectionCursor};
+
+fn parse_error<T: Input>(input: &str) -> ParseError {
+    let error: Error = match T::parse_reader(input.as_bytes()) {
+        Ok(_) => panic!("expected {input:?} to be rejected"),
+        Err(error) => error,
+    };
+    error
+        .downcast_ref::<ParseError>()
+        .unwrap_or_else(|| panic!("no ParseError in {error:?}"))
+        .clone()
+}
+
+#[test]
+fn lines_invalid_token() {
+    assert_eq!(
+        parse_error::<Lines<u32>>("1\n2\nx3\n"),
+        ParseError::InvalidToken {
+            line: 3,
+            column: 1,
+            token: "x3".to_string(),
+        }
+    );
+}
+
+#[test]
+fn list_invalid_token() {
+    assert_eq!(
+        parse_error::<List<u32>>("1,2,a,4\n"),
+        ParseError::InvalidToken {
+            line: 1,
+            column: 5,
+            token: "a".to_string(),
+        }
+    );
+}
+
+#[test]
+fn ragged_grid() {
+    assert_eq!(
+        parse_error::<Grid<char>>("abc\nabc\nab\n"),
+        ParseError::RaggedGrid {
+            line: 3,
+            expected: 3,
+            found: 2,
+        }
+    );
+}
+
+#[test]
+fn integer_out_of_range() {
+    assert_eq!(
+        parse_error::<Lines<u8>>("12\n300\n"),
+        ParseError::OutOfRange {
+            line: 2,
+            column: 1,
+            token: "300".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error::<List<i8, ' '>>("1 -129\n"),
+        ParseError::OutOfRange {
+            line: 1,
+            column: 3,
+            token: "-129".to_string(),
+        }
+    );
+}
+
+#[test]
+fn errors_in_later_sections_are_offset() {
+    assert_eq!(
+        parse_error::<(Lines<u32>, Lines<u32>)>("1\n2\n\n3\nx\n"),
+        ParseError::InvalidToken {
+            line: 5,
+            column: 1,
+            token: "x".to_string(),
+        }
+    );
+}
+
+#[test]
+fn missing_section() {
+    assert_eq!(
+        parse_error::<(Lines<u32>, Lines<u32>, Lines<u32>)>("1\n2\n\n3\n"),
+        ParseError::MissingSection {
+            line: 4,
+            name: "section 3 of 3".to_string(),
+        }
+    );
+    assert_eq!(
+        parse_error::<(Lines<u32>, Lines<u32>)>(""),
+        ParseError::MissingSection {
+            line: 0,
+            name: "section 1 of 2".to_string(),
+        }
+    );
+
+    let mut cursor = SectionCursor::new(&b"1\n2\n"[..]).unwrap();
+    cursor.section::<Lines<u32>>("numbers").unwrap();
+    let error = cursor.line::<u32>("total").unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        Some(&ParseError::MissingSection {
+            line: 2,
+            name: "total".to_string(),
+        })
+    );
+}
+
+#[test]
+fn byte_grid_rejects_non_ascii_bytes() {
+    let Err(error) = ByteGrid::parse_bytes(b"ab\na\xe9\n") else {
+        panic!("expected non-ASCII bytes to be rejected");
+    };
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        Some(&ParseError::InvalidToken {
+            line: 2,
+            column: 2,
+            token: "\u{fffd}".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_error::<ByteGrid>("ab\naé\n"),
+        ParseError::InvalidToken {
+            line: 2,
+            column: 2,
+            token: "é".to_string(),
+        }
+    );
+}
@@ -0,0 +1,34 @@
+use common::{BorrowedInput, Table};
+
+fn columns(input: &str) -> Vec<Vec<String>> {
+    let table = Table::parse_str(input).unwrap();
+    table
+        .groups()
+        .map(|group| group.columns().map(Iterator::collect).collect())
+        .collect()
+}
+
+#[test]
+fn short_rows_read_as_spaces() {
+    assert_eq!(
+        columns("12 3\n4  56\n*  +\n"),
+        [vec!["14*", "2  "], vec!["35+", " 6 "]]
+    );
+
+    let table = Table::parse_str("12 3\n4\n").unwrap();
+    let group = table.group(0);
+    assert_eq!(group.rows().collect::<Vec<_>>(), ["12", "4"]);
+    assert_eq!(group.get(1, 1), Some(' '));
+    assert_eq!(group.get(2, 0), None);
+}
+
+#[test]
+fn non_ascii_columns_count_chars() {
+    assert_eq!(
+        columns("é1 ab\nx  c\n"),
+        [vec!["éx", "1 "], vec!["ac", "b "]]
+    );
+    let table = Table::parse_str("é1 ab\nx  c\n").unwrap();
+    assert_eq!(table.group(1).row(0), "ab");
+    assert_eq!(table.group(0).get(0, 0), Some('é'));
+}
@@ -0,0 +1,170 @@
+use std::ops::Range;
+
+use proc_macro2::Span;
+use syn::{Error, Result};
+
+#[derive(Debug, PartialEq)]
+pub enum Segment {
+    Literal(String),
+    /// `placeholder` is the byte range of `{name:sep}` within the format string
+    Field {
+        name: String,
+        sep: Option<String>,
+        placeholder: Range<usize>,
+    },
+}
+
+pub fn parse_format(format: &str, span: Span) -> Result<Vec<Segment>> {
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = format.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '{' if chars.peek().is_some_and(|&(_, c)| c == '{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek().is_some_and(|&(_, c)| c == '}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                let end = loop {
+                    match chars.next() {
+                        Some((end, '}')) => break end + 1,
+                        Some((_, c)) => placeholder.push(c),
+                        None => return Err(Error::new(span, "unterminated placeholder")),
+                    }
+                };
+
+                let (name, sep) = match placeholder.split_once(':') {
+                    Some((name, "")) => {
+                        return Err(Error::new(
+                            span,
+                            format!("empty separator for field `{name}`"),
+                        ));
+                    }
+                    Some((name, sep)) => (name, Some(sep.to_string())),
+                    None => (placeholder.as_str(), None),
+                };
+                if name.is_empty() {
+                    return Err(Error::new(span, "empty placeholder"));
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                } else if let Some(Segment::Field { name: prev, .. }) = segments.last() {
+                    return Err(Error::new(
+                        span,
+                        format!("fields `{prev}` and `{name}` must be separated by a literal"),
+                    ));
+                }
+                segments.push(Segment::Field {
+                    name: name.to_string(),
+                    sep,
+                    placeholder: start..end,
+                });
+            }
+            '}' => {
+                return Err(Error::new(
+                    span,
+                    "unmatched `}`, use `}}` for a literal brace",
+                ));
+            }
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(format: &str) -> Result<Vec<Segment>> {
+        parse_format(format, Span::call_site())
+    }
+
+    fn field(name: &str, sep: Option<&str>, placeholder: Range<usize>) -> Segment {
+        Segment::Field {
+            name: name.to_string(),
+            sep: sep.map(str::to_string),
+            placeholder,
+        }
+    }
+
+    #[test]
+    fn literals_and_fields() {
+        assert_eq!(
+            parse("x={x}, y={y}").unwrap(),
+            [
+                Segment::Literal("x=".to_string()),
+                field("x", None, 2..5),
+                Segment::Literal(", y=".to_string()),
+                field("y", None, 9..12),
+            ],
+        );
+    }
+
+    #[test]
+    fn escaped_braces() {
+        assert_eq!(
+            parse("{{{values:,}}}").unwrap(),
+            [
+                Segment::Literal("{".to_string()),
+                field("values", Some(","), 2..12),
+                Segment::Literal("}".to_string()),
+            ],
+        );
+        assert_eq!(parse("{{}}").unwrap(), [Segment::Literal("{}".to_string())]);
+    }
+
+    #[test]
+    fn placeholder_ranges_count_bytes() {
+        let format = "é {a} {bc:, }";
+        let segments = parse(format).unwrap();
+        let ranges = segments
+            .iter()
+            .filter_map(|segment| match segment {
+                Segment::Field { placeholder, .. } => Some(&format[placeholder.clone()]),
+                Segment::Literal(_) => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, ["{a}", "{bc:, }"]);
+    }
+
+    #[test]
+    fn adjacent_fields() {
+        let error = parse("{a}{b}").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "fields `a` and `b` must be separated by a literal"
+        );
+    }
+
+    #[test]
+    fn empty_separator() {
+        let error = parse("[{values:}]").unwrap_err();
+        assert_eq!(error.to_string(), "empty separator for field `values`");
+    }
+
+    #[test]
+    fn malformed_placeholders() {
+        assert_eq!(
+            parse("{a").unwrap_err().to_string(),
+            "unterminated placeholder"
+        );
+        assert_eq!(parse("{}").unwrap_err().to_string(), "empty placeholder");
+        assert_eq!(
+            parse("a}").unwrap_err().to_string(),
+            "unmatched `}`, use `}}` for a literal brace"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+mod format;
+
+use std::ops::Range;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, Ident, LitStr, Result, Type, parse_macro_input,
+    spanned::Spanned,
+};
+
+use self::format::{Segment, parse_format};
+
+#[proc_macro_derive(Parse, attributes(parse))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(Input, attributes(input))]
+pub fn derive_input(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_input(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn format_attr(attrs: &[Attribute]) -> Result<Option<(Vec<Segment>, LitStr)>> {
+    attrs
+        .iter()
+        .find(|a| a.path().is_ident("parse"))
+        .map(|a| {
+            let format = a.parse_args::<LitStr>()?;
+            Ok((parse_format(&format.value(), format.span())?, format))
+        })
+        .transpose()
+}
+
+// Sub-spans need a nightly compiler and a literal without escapes, so that offsets into its value
+// line up with its source. Otherwise this falls back to the whole format string.
+fn placeholder_span(format: &LitStr, placeholder: Range<usize>) -> Span {
+    let token = format.token();
+    let source = token.to_string();
+    let plain = source.strip_prefix('"').and_then(|s| s.strip_suffix('"'));
+    plain
+        .filter(|&plain| plain == format.value())
+        .and_then(|_| token.subspan(placeholder.start + 1..placeholder.end + 1))
+        .unwrap_or_else(|| format.span())
+}
+
+struct Field {
+    name: String,
+    var: syn::Ident,
+    ty: Type,
+    span: Span,
+    used: bool,
+}
+
+fn collect_fields(fields: &Fields) -> Vec<Field> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| Field {
+            name: f
+                .ident
+                .as_ref()
+                .map_or_else(|| i.to_string(), ToString::to_string),
+            var: format_ident!("__parse_field_{i}"),
+            ty: f.ty.clone(),
+            span: f.span(),
+            used: false,
+        })
+        .collect()
+}
+
+fn parse_field(field: &Field, sep: Option<&str>) -> TokenStream2 {
+    let Field { name, var, ty, .. } = field;
+    match sep {
+        None => quote! {
+            let #var: #ty = ::common::Context::with_context(
+                ::core::str::FromStr::from_str(__parse_text).map_err(::common::Error::from),
+                || ::std::format!("invalid {} '{}'", #name, __parse_text),
+            )?;
+        },
+        // Empty text is an empty collection rather than a single empty element
+        Some(sep) => quote! {
+            let #var: #ty = (!__parse_text.is_empty())
+                .then(|| __parse_text.split(#sep))
+                .into_iter()
+                .flatten()
+                .map(|__parse_element| {
+                    ::common::Context::with_context(
+                        ::core::str::FromStr::from_str(__parse_element)
+                            .map_err(::common::Error::from),
+                        || ::std::format!("invalid {} element '{}'", #name, __parse_element),
+                    )
+                })
+                .collect::<::common::Result<_>>()?;
+        },
+    }
+}
+
+// Expands to statements which parse `__parse_input` and evaluate `constructor` on success
+fn parse_body(
+    segments: &[Segment],
+    fields: &Fields,
+    constructor: TokenStream2,
+    format: &LitStr,
+) -> Result<TokenStream2> {
+    let mut vars = collect_fields(fields);
+    let mut statements = Vec::new();
+
+    let mut segments = segments.iter().peekable();
+    while let Some(segment) = segments.next() {
+        match segment {
+            Segment::Literal(literal) => statements.push(quote! {
+                let __parse_rest = __parse_rest.strip_prefix(#literal).ok_or_else(|| {
+                    ::common::anyhow!("expected '{}' at '{}'", #literal, __parse_rest)
+                })?;
+            }),
+            Segment::Field {
+                name,
+                sep,
+                placeholder,
+            } => {
+                let span = placeholder_span(format, placeholder.clone());
+                let field = vars
+                    .iter_mut()
+                    .find(|f| f.name == *name)
+                    .ok_or_else(|| Error::new(span, format!("unknown field `{name}`")))?;
+                if field.used {
+                    return Err(Error::new(span, format!("field `{name}` used twice")));
+                }
+                field.used = true;
+
+                if let Some(Segment::Literal(literal)) = segments.peek() {
+                    segments.next();
+                    let split = quote! {
+                        __parse_rest.split_once(#literal).ok_or_else(|| {
+                            ::common::anyhow!(
+                                "expected '{}' after {} in '{}'",
+                                #literal,
+                                #name,
+                                __parse_rest,
+                            )
+                        })?
+                    };
+                    // When the next literal starts with the separator, an empty field drops it
+                    // too, so `"] {a: } {"` reads `"] {"` as well as `"]  {"`
+                    let collapsed = sep
+                        .as_deref()
+                        .and_then(|sep| literal.strip_prefix(sep))
+                        .filter(|collapsed| !collapsed.is_empty());
+                    statements.push(match collapsed {
+                        Some(collapsed) => quote! {
+                            let (__parse_text, __parse_rest) =
+                                match __parse_rest.strip_prefix(#collapsed) {
+                                    ::core::option::Option::Some(__parse_rest) => ("", __parse_rest),
+                                    ::core::option::Option::None => #split,
+                                };
+                        },
+                        None => quote! {
+                            let (__parse_text, __parse_rest) = #split;
+                        },
+                    });
+                } else {
+                    statements.push(quote! {
+                        let (__parse_text, __parse_rest) = (__parse_rest, "");
+                    });
+                }
+                statements.push(parse_field(field, sep.as_deref()));
+            }
+        }
+    }
+
+    if let Some(field) = vars.iter().find(|f| !f.used) {
+        return Err(Error::new(
+            field.span,
+            format!("field `{}` is missing from the format", field.name),
+        ));
+    }
+
+    let vars = vars.iter().map(|f| &f.var);
+    let construct = match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote! { #constructor { #(#names: #vars),* } }
+        }
+        Fields::Unnamed(_) => quote! { #constructor(#(#vars),*) },
+        Fields::Unit => constructor,
+    };
+
+    Ok(quote! {
+        let __parse_rest: &str = __parse_input;
+        #(#statements)*
+        if !__parse_rest.is_empty() {
+            ::common::bail!("unexpected trailing input '{}'", __parse_rest);
+        }
+        return ::core::result::Result::Ok(#construct);
+    })
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let (segments, format) = format_attr(&input.attrs)?.ok_or_else(|| {
+                Error::new_spanned(input, "expected a `#[parse(\"...\")]` format attribute")
+            })?;
+            parse_body(&segments, &data.fields, quote! { Self }, &format)?
+        }
+        Data::Enum(data) => {
+            let mut keyed = Vec::new();
+            let mut fallback = None;
+            for variant in &data.variants {
+                let (segments, format) = format_attr(&variant.attrs)?.ok_or_else(|| {
+                    Error::new_spanned(variant, "expected a `#[parse(\"...\")]` format attribute")
+                })?;
+                let ident = &variant.ident;
+                let body =
+                    parse_body(&segments, &variant.fields, quote! { Self::#ident }, &format)?;
+
+                match segments.first() {
+                    Some(Segment::Literal(prefix)) => keyed.push((prefix.clone(), body)),
+                    _ if fallback.is_some() => {
+                        return Err(Error::new_spanned(
+                            variant,
+                            "only one variant may omit a leading literal",
+                        ));
+                    }
+                    _ => fallback = Some(body),
+                }
+            }
+
+            // Longest prefix first, so `"++"` is tried before `"+"`
+            keyed.sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+            let prefixes = keyed
+                .iter()
+                .map(|(prefix, _)| format!("'{prefix}'"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let checks = keyed.iter().map(|(prefix, body)| {
+                quote! {
+                    if __parse_input.starts_with(#prefix) {
+                        #body
+                    }
+                }
+            });
+            let otherwise = fallback.unwrap_or_else(|| {
+                quote! {
+                    ::common::bail!("expected one of {}, found '{}'", #prefixes, __parse_input);
+                }
+            });
+
+            quote! {
+                #(#checks)*
+                #otherwise
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new_spanned(input, "unions cannot derive `Parse`"));
+        }
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = ::common::Error;
+
+            #[allow(unreachable_code)]
+            fn from_str(__parse_input: &str) -> ::common::Result<Self> {
+                #body
+            }
+        }
+    })
+}
+
+fn expand_input(input: &DeriveInput) -> Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "`Input` can only be derived for structs",
+        ));
+    };
+
+    let mut statements = Vec::new();
+    for (source, field) in data.fields.iter().zip(collect_fields(&data.fields)) {
+        let kind = match source.attrs.iter().find(|a| a.path().is_ident("input")) {
+            Some(attr) => attr.parse_args::<Ident>()?,
+            None => format_ident!("section"),
+        };
+        if kind != "section" && kind != "line" && kind != "rest" {
+            return Err(Error::new_spanned(
+                kind,
+                "expected one of `section`, `line` or `rest`",
+            ));
+        }
+
+        let Field { name, var, ty, .. } = &field;
+        let name = name.replace('_', " ");
+        statements.push(quote! {
+            let #var: #ty = __input_cursor.#kind(#name)?;
+        });
+    }
+
+    let vars = collect_fields(&data.fields).into_iter().map(|f| f.var);
+    let construct = match &data.fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| &f.ident);
+            quote! { Self { #(#names: #vars),* } }
+        }
+        Fields::Unnamed(_) => quote! { Self(#(#vars),*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::common::Input for #ident #ty_generics #where_clause {
+            fn parse_reader<R: ::std::io::BufRead>(reader: R) -> ::common::Result<Self> {
+                let mut __input_cursor = ::common::SectionCursor::new(reader)?;
+                #(#statements)*
+                __input_cursor.finish()?;
+                ::core::result::Result::Ok(#construct)
+            }
+        }
+    })
+}
@@ -1,38 +1,29 @@
-use std::str::FromStr;
+use common::{Parse, Stream, solve_streaming};
 
-use common::{Context, Lines, bail, solve};
-
-struct Rotation {
-    amount: isize,
+#[derive(Parse)]
+enum Rotation {
+    #[parse("L{0}")]
+    Left(isize),
+    #[parse("R{0}")]
+    Right(isize),
 }
 
-impl FromStr for Rotation {
-    type Err = common::Error;
-
-    fn from_str(s: &str) -> common::Result<Self> {
-        let direction = match s.get(0..1).context("missing direction")? {
-            "L" => -1,
-            "R" => 1,
-            c => bail!("invalid direction '{c}'"),
-        };
-        let distance = s
-            .get(1..)
-            .context("missing distance")?
-            .parse::<isize>()
-            .context("invalid distance")?;
-        Ok(Self {
-            amount: direction * distance,
-        })
+impl Rotation {
+    fn amount(&self) -> isize {
+        match self {
+            Self::Left(distance) => -distance,
+            Self::Right(distance) => *distance,
+        }
     }
 }
 
 fn main() -> common::Result<()> {
-    solve(
-        |input: &Lines<Rotation>| {
+    solve_streaming(
+        |input: &mut Stream<Rotation>| {
             let mut zeros = 0;
             let mut total = 50;
-            for rotation in &input.lines {
-                total = (total + rotation.amount) % 100;
+            for rotation in input {
+                total = (total + rotation.amount()) % 100;
                 if total == 0 {
                     zeros += 1;
                 }
@@ -42,8 +33,8 @@ fn main() -> common::Result<()> {
         |input| {
             let mut zeros = 0;
             let mut total = 50;
-            for rotation in &input.lines {
-                let next = total + rotation.amount;
+            for rotation in input {
+                let next = total + rotation.amount();
                 if (total > 0 && next <= 0) || (total < 0 && next >= 0) {
                     zeros += 1;
                 }
@@ -54,3 +45,15 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use common::Lines;
+
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Lines<Rotation>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,61 +1,58 @@
 use core::{f64, fmt, str::FromStr};
 
-use common::{Context, Lines, bail, solve};
+use common::{Context, Lines, Parse, bail, solve};
 
-struct Machine {
-    lights: u32,
-    buttons: Vec<u32>,
-    joltages: Vec<u32>,
-}
+struct Lights(u32);
 
-impl FromStr for Machine {
+impl FromStr for Lights {
     type Err = common::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut pieces = s.split(' ');
-        Ok(Self {
-            lights: pieces
-                .next()
-                .context("missing indicator lights")?
-                .strip_prefix('[')
-                .context("indicators missing start bracket")?
-                .strip_suffix(']')
-                .context("indicators missing end bracket")?
-                .chars()
-                .enumerate()
-                .map(|(i, c)| {
-                    Ok(match c {
+        s.chars()
+            .enumerate()
+            .try_fold(0, |acc, (i, c)| {
+                Ok(acc
+                    | match c {
                         '.' => 0,
-                        '#' => 1 << i,
+                        '#' => 1u32
+                            .checked_shl(i as u32)
+                            .context("too many indicator lights")?,
                         _ => bail!("invalid indicator light `{c}`"),
                     })
-                })
-                .try_fold::<_, _, common::Result<_>>(0, |acc, x| Ok(acc | x?))?,
-            joltages: pieces
-                .next_back()
-                .context("missing joltages")?
-                .strip_prefix('{')
-                .context("joltages missing start brace")?
-                .strip_suffix('}')
-                .context("joltages missing end brace")?
-                .split(',')
-                .map(|j| j.parse::<u32>().context("invalid joltage"))
-                .collect::<Result<_, _>>()?,
-            buttons: pieces
-                .map(|p| {
-                    p.strip_prefix('(')
-                        .context("button missing start paren")?
-                        .strip_suffix(')')
-                        .context("button missing end paren")?
-                        .split(',')
-                        .map(|s| Ok(1 << s.parse::<u32>().context("invalid button target")?))
-                        .try_fold(0, |acc, x: common::Result<_>| Ok(acc | x?))
-                })
-                .collect::<common::Result<_>>()?,
-        })
+            })
+            .map(Self)
+    }
+}
+
+struct Button(u32);
+
+impl FromStr for Button {
+    type Err = common::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.strip_prefix('(')
+            .context("button missing start paren")?
+            .strip_suffix(')')
+            .context("button missing end paren")?
+            .split(',')
+            .map(|s| {
+                let target = s.parse::<u32>().context("invalid button target")?;
+                1u32.checked_shl(target)
+                    .with_context(|| format!("button target {target} out of range"))
+            })
+            .try_fold(0, |acc, x: common::Result<_>| Ok(acc | x?))
+            .map(Self)
     }
 }
 
+#[derive(Parse)]
+#[parse("[{lights}] {buttons: } {{{joltages:,}}}")]
+struct Machine {
+    lights: Lights,
+    buttons: Vec<Button>,
+    joltages: Vec<u32>,
+}
+
 #[derive(Debug)]
 struct Matrix {
     cols: usize,
@@ -145,9 +142,9 @@ fn main() -> common::Result<()> {
                     }
 
                     let result = machine.buttons.iter().enumerate().fold(0, |acc, (i, b)| {
-                        acc ^ if p & (1 << i) != 0 { *b } else { 0 }
+                        acc ^ if p & (1 << i) != 0 { b.0 } else { 0 }
                     });
-                    if result == machine.lights {
+                    if result == machine.lights.0 {
                         min_presses = p.count_ones();
                     }
                 }
@@ -165,7 +162,7 @@ fn main() -> common::Result<()> {
                 let mut mat = Matrix::new(machine.joltages.len(), machine.buttons.len() + 1);
                 for (i, joltage) in machine.joltages.iter().enumerate() {
                     for (j, button) in machine.buttons.iter().enumerate() {
-                        let value = if button & 1 << i != 0 { 1.0 } else { 0.0 };
+                        let value = if button.0 & 1 << i != 0 { 1.0 } else { 0.0 };
                         mat.set(i, j, value);
                     }
                     mat.set(i, machine.buttons.len(), *joltage as f64);
@@ -195,7 +192,7 @@ fn main() -> common::Result<()> {
                     {
                         let max = (0..machine.joltages.len())
                             .map(|i| {
-                                if machine.buttons[j] & 1 << i != 0 {
+                                if machine.buttons[j].0 & 1 << i != 0 {
                                     machine.joltages[i]
                                 } else {
                                     0
@@ -259,3 +256,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Lines<Machine>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,44 +1,35 @@
-use std::{collections::HashMap, io};
+use std::collections::HashMap;
 
-use common::{Context as _, Input, solve};
+use common::{BorrowedInput, keyed_lines, solve_prepared};
 
-struct Graph {
-    nodes: HashMap<String, Vec<String>>,
+struct Graph<'a> {
+    nodes: HashMap<&'a str, Vec<&'a str>>,
 }
 
-impl Input for Graph {
-    fn parse_reader<R: io::BufRead>(reader: R) -> common::Result<Self> {
-        let mut nodes = HashMap::new();
-
-        for line in reader.lines() {
-            let line = line?;
-            let (node, rest) = line.split_once(": ").context("expeced colon separator")?;
-            nodes.insert(
-                node.to_string(),
-                rest.split(' ').map(str::to_string).collect(),
-            );
-        }
-
-        Ok(Self { nodes })
+impl<'a> BorrowedInput<'a> for Graph<'a> {
+    fn parse_str(input: &'a str) -> common::Result<Self> {
+        Ok(Self {
+            nodes: keyed_lines(input).collect::<common::Result<_>>()?,
+        })
     }
 }
 
-fn toposort(graph: &Graph) -> Vec<&String> {
+fn toposort<'a>(graph: &Graph<'a>) -> Vec<&'a str> {
     let mut incoming = HashMap::new();
-    for (node, edges) in &graph.nodes {
+    for (&node, edges) in &graph.nodes {
         incoming.entry(node).or_insert(0);
-        for edge in edges {
+        for &edge in edges {
             *incoming.entry(edge).or_insert(0) += 1;
         }
     }
     let mut sorted = incoming
         .iter()
-        .filter_map(|(node, incoming)| (*incoming == 0).then_some(*node))
+        .filter_map(|(&node, &incoming)| (incoming == 0).then_some(node))
         .collect::<Vec<_>>();
     let mut current = 0;
     while current < sorted.len() {
         if let Some(edges) = graph.nodes.get(sorted[current]) {
-            for edge in edges {
+            for &edge in edges {
                 *incoming.get_mut(edge).unwrap() -= 1;
                 if incoming[edge] == 0 {
                     sorted.push(edge);
@@ -53,17 +44,17 @@ fn toposort(graph: &Graph) -> Vec<&String> {
 }
 
 fn main() -> common::Result<()> {
-    solve(
-        |input: &Graph| {
-            let sorted = toposort(input);
+    solve_prepared(
+        |input: &Graph<'_>| toposort(input),
+        |input, sorted| {
             let mut routes_to_node = HashMap::new();
             routes_to_node.insert("you", 1);
 
-            for &node in &sorted {
+            for &node in sorted {
                 let routes = *routes_to_node.entry(node).or_insert(0);
 
                 if let Some(edges) = input.nodes.get(node) {
-                    for edge in edges {
+                    for &edge in edges {
                         *routes_to_node.entry(edge).or_insert(0) += routes;
                     }
                 }
@@ -71,7 +62,7 @@ fn main() -> common::Result<()> {
 
             routes_to_node["out"]
         },
-        |input| {
+        |input, sorted| {
             #[derive(Clone, Default)]
             struct Routes {
                 counts_none: u64,
@@ -80,7 +71,6 @@ fn main() -> common::Result<()> {
                 counts_both: u64,
             }
 
-            let sorted = toposort(input);
             let mut routes_to_node = HashMap::new();
             routes_to_node.insert(
                 "svr",
@@ -92,16 +82,16 @@ fn main() -> common::Result<()> {
                 },
             );
 
-            for &node in &sorted {
+            for &node in sorted {
                 let from_routes = routes_to_node
                     .entry(node)
                     .or_insert(Routes::default())
                     .clone();
 
                 if let Some(edges) = input.nodes.get(node) {
-                    for edge in edges {
+                    for &edge in edges {
                         let to_routes = routes_to_node.entry(edge).or_insert(Routes::default());
-                        match node.as_str() {
+                        match node {
                             "fft" => {
                                 to_routes.counts_fft += from_routes.counts_none;
                                 to_routes.counts_both += from_routes.counts_dac;
@@ -125,3 +115,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Graph>(include_str!("../test_1.input"), 1000).unwrap();
+    }
+}
@@ -1,11 +1,48 @@
 use std::io;
 
-use common::{Context as _, Input, bail, solve};
+use common::{
+    Context as _, Input, KeyedLines, Pair, Section, Snippet, Unsolved, anyhow, solve,
+    split_sections,
+};
 
 struct Present {
     cells: [bool; 9],
 }
 
+impl Input for Present {
+    fn parse_reader<R: io::BufRead>(reader: R) -> common::Result<Self> {
+        let mut lines = reader.lines();
+        lines.next().context("expected present index")??;
+
+        let mut present = Present { cells: [false; 9] };
+        for y in 0..3 {
+            let line = lines.next().context("expected present line")??;
+            if line.chars().count() != 3 {
+                return Err(anyhow!("expected 3 cells in present line"))
+                    .context(Snippet::line(y + 2, &line));
+            }
+            for (i, c) in line.chars().enumerate() {
+                present.cells[i + y * 3] =
+                    match c {
+                        '#' => true,
+                        '.' => false,
+                        _ => {
+                            return Err(anyhow!("invalid present cell '{c}'"))
+                                .context(Snippet::span(y + 2, &line, i, 1));
+                        }
+                    };
+            }
+        }
+
+        if let Some(line) = lines.next() {
+            return Err(anyhow!("unexpected content after present definition"))
+                .context(Snippet::line(5, &line?));
+        }
+
+        Ok(present)
+    }
+}
+
 struct TreeArea {
     width: u32,
     height: u32,
@@ -19,46 +56,25 @@ struct Problem {
 
 impl Input for Problem {
     fn parse_reader<R: io::BufRead>(reader: R) -> common::Result<Self> {
-        let mut presents = Vec::new();
-        let mut tree_areas = Vec::new();
-
-        let mut lines = reader.lines();
-        while let Some(line) = lines.next() {
-            let line = line?;
-
-            if !line.contains('x') {
-                // Present
-                let mut present = Present { cells: [false; 9] };
-                for y in 0..3 {
-                    let line = lines.next().context("expected present line")??;
-                    for (i, c) in line.chars().enumerate() {
-                        present.cells[i + y * 3] = c == '#';
-                    }
-                }
-
-                if !lines
-                    .next()
-                    .context("expected newline after present")??
-                    .is_empty()
-                {
-                    bail!("unexpected content after present definition");
-                }
-
-                presents.push(present);
-            } else {
-                // Tree area
-                let (width, rest) = line.split_once('x').context("expected width")?;
-                let (height, rest) = rest.split_once(": ").context("expected height")?;
-                tree_areas.push(TreeArea {
-                    width: width.parse().context("invalid tree area width")?,
-                    height: height.parse().context("invalid tree area height")?,
-                    counts: rest.split(' ').map(str::parse).collect::<Result<_, _>>()?,
-                });
-            }
-        }
+        let mut sections = split_sections(reader)?;
+        let tree_areas = sections
+            .pop()
+            .context("missing tree areas")?
+            .parse::<KeyedLines<Pair<u32, u32, 'x'>, u32>>()?
+            .lines
+            .into_iter()
+            .map(|(Pair(width, height), counts)| TreeArea {
+                width,
+                height,
+                counts,
+            })
+            .collect();
 
         Ok(Self {
-            presents,
+            presents: sections
+                .iter()
+                .map(Section::parse)
+                .collect::<common::Result<_>>()?,
             tree_areas,
         })
     }
@@ -82,6 +98,16 @@ fn main() -> common::Result<()> {
             }
             possible
         },
-        |_input| 0,
+        |_input| Unsolved,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Problem>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use common::{Context as _, List, solve};
+use common::{List, Pair, solve};
 
 struct ProductRange {
     start: usize,
@@ -11,11 +11,8 @@ impl FromStr for ProductRange {
     type Err = common::Error;
 
     fn from_str(s: &str) -> common::Result<Self> {
-        let (start, end) = s.split_once("-").context("invalid product id range")?;
-        Ok(Self {
-            start: start.parse().context("invalid start")?,
-            end: end.parse().context("invalid end")?,
-        })
+        let Pair(start, end) = s.parse::<Pair<_, _, '-'>>()?;
+        Ok(Self { start, end })
     }
 }
 
@@ -92,3 +89,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<List<ProductRange>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use common::{Lines, solve};
+use common::{Context as _, Stream, solve_streaming};
 
 struct Bank {
     joltages: Vec<u8>,
@@ -11,7 +11,14 @@ impl FromStr for Bank {
 
     fn from_str(s: &str) -> common::Result<Self> {
         Ok(Self {
-            joltages: s.chars().map(|c| c as u8 - b'0').collect(),
+            joltages: s
+                .chars()
+                .map(|c| {
+                    c.to_digit(10)
+                        .map(|d| d as u8)
+                        .with_context(|| format!("invalid joltage '{c}'"))
+                })
+                .collect::<common::Result<_>>()?,
         })
     }
 }
@@ -30,20 +37,20 @@ fn joltage(bank: &Bank, n: usize) -> usize {
 }
 
 fn main() -> common::Result<()> {
-    solve(
-        |input: &Lines<Bank>| {
-            input
-                .lines
-                .iter()
-                .map(|bank| joltage(bank, 2))
-                .sum::<usize>()
-        },
-        |input| {
-            input
-                .lines
-                .iter()
-                .map(|bank| joltage(bank, 12))
-                .sum::<usize>()
-        },
+    solve_streaming(
+        |input: &mut Stream<Bank>| input.map(|bank| joltage(&bank, 2)).sum::<usize>(),
+        |input| input.map(|bank| joltage(&bank, 12)).sum::<usize>(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use common::Lines;
+
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Lines<Bank>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,6 +1,6 @@
-use common::{Grid, bail, solve};
+use common::{Grid, Point2, bail, solve};
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum Tile {
     None,
     Paper,
@@ -18,13 +18,22 @@ impl TryFrom<char> for Tile {
     }
 }
 
-fn is_accessible(grid: &Grid<Tile>, x: usize, y: usize) -> bool {
-    *grid.get(x, y).unwrap() == Tile::Paper
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::None => '.',
+            Tile::Paper => '@',
+        }
+    }
+}
+
+fn is_accessible(grid: &Grid<Tile>, point: Point2) -> bool {
+    grid[point] == Tile::Paper
         && grid
-            .adjacent(x, y)
-            .filter(|&(nx, ny)| *grid.get(nx, ny).unwrap() == Tile::Paper)
+            .neighbors8(point)
+            .filter(|&(_, tile)| *tile == Tile::Paper)
             .count()
-            <= 4
+            < 4
 }
 
 fn main() -> common::Result<()> {
@@ -32,7 +41,7 @@ fn main() -> common::Result<()> {
         |input: &Grid<Tile>| {
             input
                 .iter()
-                .filter(|&(x, y)| is_accessible(input, x, y))
+                .filter(|&p| is_accessible(input, p.into()))
                 .count()
         },
         |input| {
@@ -43,9 +52,9 @@ fn main() -> common::Result<()> {
             while !is_finished {
                 is_finished = true;
 
-                for (x, y) in grid.iter() {
-                    if is_accessible(&grid, x, y) {
-                        grid.set(x, y, Tile::None);
+                for point in grid.iter().map(Point2::from) {
+                    if is_accessible(&grid, point) {
+                        grid[point] = Tile::None;
                         removed += 1;
                         is_finished = false;
                     }
@@ -56,3 +65,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Grid<Tile>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
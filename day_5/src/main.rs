@@ -1,6 +1,6 @@
-use std::{io, str::FromStr};
+use std::str::FromStr;
 
-use common::{Context as _, Input, solve};
+use common::{Input, Lines, Pair, solve};
 
 #[derive(Clone)]
 struct Range {
@@ -12,43 +12,15 @@ impl FromStr for Range {
     type Err = common::Error;
 
     fn from_str(s: &str) -> common::Result<Self> {
-        let (start, end) = s.split_once('-').context("expected range to contain '-'")?;
-        Ok(Self {
-            start: start.parse().context("invalid start index")?,
-            end: end.parse().context("invalid end index")?,
-        })
+        let Pair(start, end) = s.parse::<Pair<_, _, '-'>>()?;
+        Ok(Self { start, end })
     }
 }
 
+#[derive(Input)]
 struct Database {
-    fresh: Vec<Range>,
-    available: Vec<usize>,
-}
-
-impl Input for Database {
-    fn parse_reader<R: io::BufRead>(reader: R) -> common::Result<Self> {
-        let mut lines = reader.lines();
-
-        let mut fresh = Vec::new();
-        for line in lines.by_ref() {
-            let line = line.context("unexpected end of input")?;
-            if line.is_empty() {
-                break;
-            }
-
-            fresh.push(line.parse().context("invalid range")?);
-        }
-
-        let available = lines
-            .map(|s| {
-                s.context("unexpected end of input")?
-                    .parse()
-                    .context("invalid ingredient")
-            })
-            .collect::<Result<_, _>>()?;
-
-        Ok(Self { fresh, available })
-    }
+    fresh: Lines<Range>,
+    available: Lines<usize>,
 }
 
 fn main() -> common::Result<()> {
@@ -56,12 +28,13 @@ fn main() -> common::Result<()> {
         |input: &Database| {
             input
                 .available
+                .lines
                 .iter()
-                .filter(|&&a| input.fresh.iter().any(|f| f.start <= a && a <= f.end))
+                .filter(|&&a| input.fresh.lines.iter().any(|f| f.start <= a && a <= f.end))
                 .count()
         },
         |input| {
-            let mut ranges = input.fresh.clone();
+            let mut ranges = input.fresh.lines.clone();
             ranges.sort_by_key(|r| r.start);
 
             let mut total = 0;
@@ -81,3 +54,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Database>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
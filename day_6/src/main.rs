@@ -1,100 +1,105 @@
-use std::{io, str::FromStr};
-
-use common::{Context as _, Input, bail, solve};
+use common::{BorrowedInput, Context as _, Parse, Snippet, Table, bail, solve};
 
+#[derive(Parse)]
 enum Op {
+    #[parse("+")]
     Add,
+    #[parse("*")]
     Multiply,
 }
 
-impl FromStr for Op {
-    type Err = common::Error;
-
-    fn from_str(s: &str) -> common::Result<Self> {
-        Ok(match s {
-            "+" => Self::Add,
-            "*" => Self::Multiply,
-            _ => bail!("invalid operation '{s}'"),
-        })
+impl Op {
+    fn apply(&self, operands: &[usize]) -> usize {
+        match self {
+            Op::Add => operands.iter().sum(),
+            Op::Multiply => operands.iter().product(),
+        }
     }
 }
 
+struct Problem {
+    op: Op,
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+}
+
 struct Problems {
-    operands: Vec<Vec<String>>,
-    operators: Vec<Op>,
+    problems: Vec<Problem>,
 }
 
-impl Input for Problems {
-    fn parse_reader<R: io::BufRead>(reader: R) -> common::Result<Self> {
-        let mut lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
-        let ops_line = lines.pop().context("expected operators line")?;
+impl BorrowedInput<'_> for Problems {
+    fn parse_str(input: &str) -> common::Result<Self> {
+        let table = Table::parse_str(input)?;
+        let Some(ops_line) = table.height().checked_sub(1).filter(|&line| line > 0) else {
+            bail!("expected operand rows above the operator row");
+        };
 
-        let mut operands = vec![Vec::new(); lines.len()];
-        let mut operators = Vec::new();
+        let problems = table
+            .groups()
+            .map(|group| {
+                let op = group.row(ops_line).trim().parse().with_context(|| {
+                    Snippet::span(ops_line + 1, table.row(ops_line), group.left(), 1)
+                })?;
+                let operands = group.row_range(0..ops_line);
 
-        let mut pos = 0;
-        while pos < ops_line.len() {
-            let next_pos = ops_line[pos + 1..]
-                .find(['+', '*'])
-                .map(|p| p + pos + 1)
-                .unwrap_or(ops_line.len() + 1);
-            for (i, line) in lines.iter().enumerate() {
-                operands[i].push(line[pos..next_pos - 1].to_string());
-            }
-            operators.push(ops_line[pos..=pos].parse()?);
-            pos = next_pos;
-        }
+                let rows = operands
+                    .rows()
+                    .enumerate()
+                    .map(|(y, row)| {
+                        row.trim().parse().with_context(|| {
+                            Snippet::span(y + 1, table.row(y), group.left(), group.width())
+                        })
+                    })
+                    .collect::<common::Result<_>>()?;
+
+                // A column reads top to bottom, so point at the first cell which is not a digit
+                let columns = operands
+                    .columns()
+                    .enumerate()
+                    .map(|(x, column)| {
+                        let column = column.collect::<String>();
+                        column.trim().parse().with_context(|| {
+                            let y = column
+                                .find(|c: char| !c.is_ascii_digit() && c != ' ')
+                                .unwrap_or(0);
+                            Snippet::span(y + 1, table.row(y), group.left() + x, 1)
+                        })
+                    })
+                    .collect::<common::Result<_>>()?;
 
-        Ok(Self {
-            operands,
-            operators,
-        })
+                Ok(Problem { op, rows, columns })
+            })
+            .collect::<common::Result<_>>()?;
+
+        Ok(Self { problems })
     }
 }
 
 fn main() -> common::Result<()> {
     solve(
         |input: &Problems| {
-            let mut total = 0;
-            for (i, op) in input.operators.iter().enumerate() {
-                let operands = input
-                    .operands
-                    .iter()
-                    .map(|v| v[i].trim().parse::<usize>().unwrap());
-                let result = match op {
-                    Op::Add => operands.sum::<usize>(),
-                    Op::Multiply => operands.product(),
-                };
-                total += result;
-            }
-            total
+            input
+                .problems
+                .iter()
+                .map(|problem| problem.op.apply(&problem.rows))
+                .sum::<usize>()
         },
         |input| {
-            let mut total = 0;
-            for i in 0..input.operators.len() {
-                let mut result = match input.operators[i] {
-                    Op::Add => 0,
-                    Op::Multiply => 1,
-                };
-                for j in 0..input.operands[0][i].len() {
-                    let mut operand = 0;
-                    for k in 0..input.operands.len() {
-                        let c = input.operands[k][i][j..=j].chars().next().unwrap();
-                        if c != ' ' {
-                            operand = operand * 10 + (c as u8 - b'0') as usize;
-                        }
-                    }
-
-                    println!("operand: {operand}");
-
-                    match input.operators[i] {
-                        Op::Add => result += operand,
-                        Op::Multiply => result *= operand,
-                    }
-                }
-                total += result;
-            }
-            total
+            input
+                .problems
+                .iter()
+                .map(|problem| problem.op.apply(&problem.columns))
+                .sum::<usize>()
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Problems>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,49 +1,49 @@
-use std::cmp;
+use std::{cmp, str::FromStr};
 
-use common::{Context, Input, solve};
+use common::{Context as _, Input, Lines, solve};
 
-struct Manifold {
-    start_pos: usize,
-    rows: Vec<Vec<usize>>,
-}
+struct Start(usize);
 
-impl Input for Manifold {
-    fn parse_reader<R: std::io::BufRead>(reader: R) -> common::Result<Self> {
-        let mut lines = reader.lines();
-        let start_pos = lines
-            .next()
-            .context("missing start line")??
-            .chars()
+impl FromStr for Start {
+    type Err = common::Error;
+
+    fn from_str(s: &str) -> common::Result<Self> {
+        s.chars()
             .position(|c| c == 'S')
-            .context("missing start position")?;
-
-        // burn an empty line
-        lines.next().context("unexpected end of input")??;
-
-        let mut rows = Vec::new();
-        while let Some(line) = lines.next() {
-            rows.push(
-                line?
-                    .chars()
-                    .enumerate()
-                    .filter_map(|(i, c)| (c == '^').then_some(i))
-                    .collect(),
-            );
-
-            // burn an empty line
-            lines.next().context("unexpected end of input")??;
-        }
-
-        Ok(Self { start_pos, rows })
+            .map(Self)
+            .context("missing start position")
+    }
+}
+
+struct Splitters(Vec<usize>);
+
+impl FromStr for Splitters {
+    type Err = common::Error;
+
+    fn from_str(s: &str) -> common::Result<Self> {
+        Ok(Self(
+            s.chars()
+                .enumerate()
+                .filter_map(|(i, c)| (c == '^').then_some(i))
+                .collect(),
+        ))
     }
 }
 
+#[derive(Input)]
+struct Manifold {
+    #[input(line)]
+    start: Start,
+    #[input(rest)]
+    rows: Lines<Splitters>,
+}
+
 fn main() -> common::Result<()> {
     solve(
         |input: &Manifold| {
-            let mut beams = vec![input.start_pos];
+            let mut beams = vec![input.start.0];
             let mut splits = 0;
-            for row in &input.rows {
+            for Splitters(row) in &input.rows.lines {
                 let mut next_beams = Vec::new();
 
                 let mut current_beam = 0;
@@ -77,8 +77,8 @@ fn main() -> common::Result<()> {
             splits
         },
         |input| {
-            let mut beams = vec![(input.start_pos, 1)];
-            for row in &input.rows {
+            let mut beams = vec![(input.start.0, 1)];
+            for Splitters(row) in &input.rows.lines {
                 let mut next_beams = Vec::new();
 
                 let mut current_beam = 0;
@@ -123,3 +123,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Manifold>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,39 +1,15 @@
-use core::str::FromStr;
 use std::collections::{HashMap, HashSet};
 
-use common::{Context, Lines, solve};
+use common::{Lines, Parse, solve_prepared};
 
+#[derive(Parse)]
+#[parse("{x},{y},{z}")]
 struct Point {
     x: i64,
     y: i64,
     z: i64,
 }
 
-impl FromStr for Point {
-    type Err = common::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut pieces = s.split(',');
-        Ok(Self {
-            x: pieces
-                .next()
-                .context("missing X coordinate")?
-                .parse()
-                .context("invalid X coordinate")?,
-            y: pieces
-                .next()
-                .context("missing Y coordinate")?
-                .parse()
-                .context("invalid Y coordinate")?,
-            z: pieces
-                .next()
-                .context("missing Z coordinate")?
-                .parse()
-                .context("invalid Z coordinate")?,
-        })
-    }
-}
-
 impl Point {
     fn sq_dist(a: &Point, b: &Point) -> i64 {
         let dx = b.x - a.x;
@@ -55,11 +31,11 @@ fn compute_sq_dists(points: &[Point]) -> Vec<(i64, usize, usize)> {
 }
 
 fn main() -> common::Result<()> {
-    solve(
-        |input: &Lines<Point>| {
+    solve_prepared(
+        |input: &Lines<Point>| compute_sq_dists(&input.lines),
+        |input, sq_dists| {
             let connect_count = if input.lines.len() == 20 { 10 } else { 1000 };
 
-            let sq_dists = compute_sq_dists(&input.lines);
             let mut edges = HashMap::new();
             for &(_, i, j) in sq_dists.iter().take(connect_count) {
                 edges.entry(i).or_insert(Vec::new()).push(j);
@@ -92,12 +68,12 @@ fn main() -> common::Result<()> {
                 * component_sizes.pop().unwrap()
                 * component_sizes.pop().unwrap()
         },
-        |input| {
-            let mut sq_dists = compute_sq_dists(&input.lines).into_iter();
+        |input, sq_dists| {
+            let mut sq_dists = sq_dists.iter();
             let mut representatives = Vec::from_iter(0..input.lines.len());
             let mut connected = 0;
             loop {
-                let (_, from, to) = sq_dists.next().unwrap();
+                let &(_, from, to) = sq_dists.next().unwrap();
 
                 let from_rep = representatives[from];
                 let to_rep = representatives[to];
@@ -124,3 +100,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Lines<Point>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,32 +1,15 @@
-use core::{iter, str::FromStr};
+use core::iter;
 
-use common::{Context as _, Lines, solve};
-
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl FromStr for Point {
-    type Err = common::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (x, y) = s.split_once(',').context("invalid coordinates")?;
-        Ok(Self {
-            x: x.parse().context("failed to parse X coordinate")?,
-            y: y.parse().context("failed to parse Y coordinate")?,
-        })
-    }
-}
+use common::{Lines, Point2, solve};
 
 fn main() -> common::Result<()> {
     solve(
-        |input: &Lines<Point>| {
+        |input: &Lines<Point2>| {
             let mut max = 0;
             for (i, a) in input.lines.iter().enumerate() {
                 for b in &input.lines[i + 1..] {
-                    let area = ((b.x as isize - a.x as isize).abs() + 1)
-                        * ((b.y as isize - a.y as isize).abs() + 1);
+                    let d = *b - *a;
+                    let area = (d.x.abs() + 1) * (d.y.abs() + 1);
                     max = isize::max(max, area);
                 }
             }
@@ -44,7 +27,7 @@ fn main() -> common::Result<()> {
             let small_points = input
                 .lines
                 .iter()
-                .map(|p| Point {
+                .map(|p| Point2 {
                     x: x_coords.binary_search(&p.x).unwrap() * 2,
                     y: y_coords.binary_search(&p.y).unwrap() * 2,
                 })
@@ -151,3 +134,13 @@ fn main() -> common::Result<()> {
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Lines<Point2>>(include_str!("../test.input"), 1000).unwrap();
+    }
+}
@@ -1,5 +1,15 @@
-use common::{Lines, solve};
+use common::{Lines, Unsolved, solve};
 
 fn main() -> common::Result<()> {
-    solve(|_input: &Lines<String>| 0, |_input| 0)
+    solve(|_input: &Lines<String>| Unsolved, |_input| Unsolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzz() {
+        common::fuzz::<Lines<String>>(include_str!("../test.input"), 1000).unwrap();
+    }
 }